@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+//--------------  Platform  -------------------
+
+//Max platform fee percentage a FeeManager can set
+pub const MAX_FEE_PERCENTAGE: u8 = 20;
+
+//Max share of each sale fee routed to the reviewers of the paper (basis points)
+pub const MAX_REVIEWER_FEE_SHARE_BPS: u16 = 10_000;
+
+//Scale of the per-review reward accumulator
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+//--------------  Reviewer reputation  -------------------
+
+//Weights are in hundredths, a brand new reviewer counts as 1.00
+pub const BASE_REVIEW_WEIGHT: u64 = 100;
+pub const WEIGHT_PER_REVIEW: u64 = 10;
+pub const MAX_WEIGHTED_REVIEWS: u64 = 50;
+pub const WEIGHT_PER_BADGE: u64 = 50;
+pub const MAX_WEIGHTED_BADGES: u64 = 10;
+pub const WEIGHT_PER_PEER_VOTE: u64 = 5;
+pub const MAX_WEIGHTED_PEER_VOTES: u64 = 20;
+pub const MIN_REVIEW_WEIGHT: u64 = 50;
+pub const MAX_REVIEW_WEIGHT: u64 = 1_000;
+
+//Achievement records whose badge counts in the reputation, each one once. Badges for other records still print
+pub const BADGE_TIERS: [u32; 6] = [1, 5, 10, 25, 50, 100];
+
+//--------------  Moderation  -------------------
+
+//Time the owner has to appeal a takedown (7 days in seconds)
+pub const APPEAL_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+//--------------  Review rounds  -------------------
+
+//Max length of the commit and of the reveal phase (30 days in seconds)
+pub const MAX_ROUND_PHASE_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+//--------------  Review invitations  -------------------
+
+//Max time an invited reviewer gets to submit (60 days in seconds)
+pub const MAX_INVITATION_SECONDS: u64 = 60 * 24 * 60 * 60;
+
+//Time a sealed invited review waits at most for the decision, and then has to be revealed in (30 days in seconds)
+pub const INVITED_REVEAL_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+//--------------  Reviewer stake  -------------------
+
+//Time between an unstake request and the stake being withdrawable (7 days in seconds)
+pub const STAKE_COOLDOWN_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+//Time between two affiliation changes, so a reviewer can't step out of an excluded institution for one review (30 days in seconds)
+pub const AFFILIATION_COOLDOWN_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+//--------------  Review bounties  -------------------
+
+//Max time a bounty stays open before the funder can take back what is left (180 days in seconds)
+pub const MAX_BOUNTY_DURATION_SECONDS: u64 = 180 * 24 * 60 * 60;
+
+//--------------  Sales analytics  -------------------
+
+//Currency key used to record sales paid in native SOL
+pub const NATIVE_CURRENCY: Pubkey = anchor_lang::system_program::ID;
+
+//Length of a sales period (30 days in seconds)
+pub const SALES_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+//Number of 30-day periods kept in the rolling sales buckets (~1 year)
+pub const SALES_PERIODS: usize = 12;
+
+//Max different currencies tracked per paper
+pub const MAX_SALE_CURRENCIES: usize = 4;
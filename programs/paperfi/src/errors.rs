@@ -28,4 +28,166 @@ pub enum ErrorCode {
     AdminAlreadyExists,
     #[msg("A publisher can't by a paper that he already owns")]
     PublisherCantBuy,
+    #[msg("Max currencies tracked for this paper have been reached")]
+    TooManyCurrencies,
+    #[msg("Control and invisible characters are not allowed")]
+    ControlCharacterNotAllowed,
+    #[msg("Bidirectional override characters are not allowed")]
+    BidiOverrideNotAllowed,
+    #[msg("The input string contains characters from an unsupported script")]
+    UnsupportedScript,
+    #[msg("URI must be in the form scheme://host/path")]
+    MalformedUri,
+    #[msg("URI can't contain whitespace")]
+    UriWhitespace,
+    #[msg("URI scheme is not allowed")]
+    UriSchemeNotAllowed,
+    #[msg("URI host is not an approved gateway")]
+    UriHostNotAllowed,
+    #[msg("Max URI rules allowed have been reached")]
+    TooManyUriRules,
+    #[msg("Fee is above the maximum allowed")]
+    InvalidFee,
+    #[msg("This paper has been taken down by a moderator")]
+    PaperTakenDown,
+    #[msg("The report has already been resolved")]
+    ReportAlreadyResolved,
+    #[msg("This report does not belong to the paper")]
+    ReportMismatch,
+    #[msg("The paper is not taken down")]
+    PaperNotTakenDown,
+    #[msg("The appeal window is closed")]
+    AppealWindowClosed,
+    #[msg("There is no pending appeal for this paper")]
+    NoPendingAppeal,
+    #[msg("The new verdict is the same as the current one")]
+    VerdictUnchanged,
+    #[msg("This paper was unlisted by its reviews and can't be listed by the owner")]
+    DelistedByReviews,
+    #[msg("Review policy needs a quorum of at least one review and a threshold up to 10000 bps")]
+    InvalidReviewPolicy,
+    #[msg("The paper is not flagged by its reviews")]
+    PaperNotFlagged,
+    #[msg("Review scores must be between 1 and 5")]
+    InvalidScore,
+    #[msg("Reviews for this paper are collected through a commit-reveal round")]
+    ReviewRoundActive,
+    #[msg("There is no open review round for this paper")]
+    NoReviewRound,
+    #[msg("Review round phases must be between 1 second and 30 days")]
+    InvalidRoundDuration,
+    #[msg("The commit phase of the review round is closed")]
+    CommitPhaseClosed,
+    #[msg("The reveal phase of the review round is not open")]
+    RevealPhaseNotOpen,
+    #[msg("The revealed review does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("The commitment can still be revealed")]
+    CommitmentNotExpired,
+    #[msg("The linked version is above the current paper version")]
+    InvalidLinkedVersion,
+    #[msg("Paper version must be above the current version")]
+    InvalidPaperVersion,
+    #[msg("The response has already been acknowledged")]
+    ResponseAlreadyAcknowledged,
+    #[msg("This review counts towards a badge and can't be withdrawn")]
+    ReviewUsedForBadge,
+    #[msg("This review has been rewarded and can't be withdrawn")]
+    ReviewRewarded,
+    #[msg("This review judged an older version of the paper")]
+    ReviewVersionStale,
+    #[msg("The version archive account is passed only, and always, when publishing a new version")]
+    VersionArchiveMismatch,
+    #[msg("Max excluded reviewers allowed have been reached")]
+    TooManyConflicts,
+    #[msg("The reviewer is excluded by the paper conflict list")]
+    ConflictOfInterest,
+    #[msg("The reviewer account is too new to review this paper")]
+    AccountTooNew,
+    #[msg("The reviewer has not published enough papers to review this paper")]
+    NotEnoughPapers,
+    #[msg("The reviewer must hold a badge from the required collection")]
+    MissingRequiredBadge,
+    #[msg("The reviewer must be a member of the required editorial board")]
+    NotBoardMember,
+    #[msg("Bounties need a payout, a number of reviews and a duration up to 180 days")]
+    InvalidBounty,
+    #[msg("SPL bounties need the mint, the token accounts and the token program")]
+    MissingTokenAccounts,
+    #[msg("The token account does not match the bounty")]
+    BountyMismatch,
+    #[msg("The bounty has not expired yet")]
+    BountyNotExpired,
+    #[msg("Reviewer fee share is above 100%")]
+    InvalidFeeShare,
+    #[msg("There are no rewards to claim")]
+    NothingToClaim,
+    #[msg("The reviewer must have the minimum stake locked")]
+    StakeRequired,
+    #[msg("The stake is still in its cooldown")]
+    StakeLocked,
+    #[msg("Request the unstake first")]
+    UnstakeNotRequested,
+    #[msg("The amount is above the stake")]
+    InsufficientStake,
+    #[msg("This report does not target a review")]
+    NotAReviewReport,
+    #[msg("Only the paper owner and buyers can rate its reviews")]
+    NotPaperReader,
+    #[msg("The paper is already published in a journal")]
+    AlreadyInJournal,
+    #[msg("Only the journal editors can decide submissions")]
+    NotJournalEditor,
+    #[msg("The submission was already decided")]
+    SubmissionDecided,
+    #[msg("Invalid deadline")]
+    InvalidDeadline,
+    #[msg("The invitation is no longer pending")]
+    InvitationNotPending,
+    #[msg("The invitation deadline has not passed yet")]
+    InvitationNotExpired,
+    #[msg("The reviewer pool is full")]
+    TooManyReviewers,
+    #[msg("Invalid number of reviewers to draw")]
+    InvalidDrawSize,
+    #[msg("Not enough eligible reviewers in the pool")]
+    NotEnoughCandidates,
+    #[msg("The paper was not delisted by its reviews")]
+    NotDelistedByReviews,
+    #[msg("This wallet already voted")]
+    AlreadyVoted,
+    #[msg("A dispute needs two papers of different owners")]
+    InvalidDispute,
+    #[msg("The dispute was already ruled")]
+    DisputeClosed,
+    #[msg("This wallet is not an admin")]
+    AdminNotFound,
+    #[msg("The last admin can not be removed")]
+    LastAdmin,
+    #[msg("The responder account does not match the review response")]
+    ResponderMismatch,
+    #[msg("Changing the paper content requires a new version")]
+    VersionBumpRequired,
+    #[msg("The affiliation was changed recently")]
+    AffiliationLocked,
+    #[msg("Authors can't decide on their own paper")]
+    EditorIsAuthor,
+    #[msg("The paper has a new version since it was submitted")]
+    StaleSubmission,
+    #[msg("Invitations still hold reservations on the bounty")]
+    BountyReserved,
+    #[msg("Reviewer pools of a journal only draw for papers submitted to it")]
+    NotSubmittedToJournal,
+    #[msg("Candidate accounts don't match the pool reviewers")]
+    InvalidCandidateAccounts,
+    #[msg("The remaining vote on the appeal is kept for a moderator")]
+    ModeratorVoteRequired,
+    #[msg("Price and listing are frozen while a dispute ruling redirects the sales")]
+    ProceedsRedirected,
+    #[msg("Reviewer requirements can't change once the version has reviews")]
+    RequirementsLocked,
+    #[msg("The quorum must be between 1 and a majority of the dispute panel")]
+    InvalidQuorum,
+    #[msg("The sales of the paper are not redirected")]
+    ProceedsNotRedirected,
 }
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use super::Role;
+
+//Single authorization check for privileged instructions.
+//Config admins are the bootstrap SuperAdmins, every other wallet needs a RoleGrant holding the role.
+pub fn authorize(
+    config: &PaperFiConfig,
+    signer: &Pubkey,
+    role_grant: Option<&RoleGrant>,
+    role: Role
+) -> Result<()> {
+    if config.admins.contains(signer) {
+        return Ok(());
+    }
+
+    match role_grant {
+        Some(grant) if grant.wallet == *signer && grant.has_role(role) => Ok(()),
+        _ => err!(ErrorCode::Unauthorized),
+    }
+}
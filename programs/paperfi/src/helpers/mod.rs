@@ -1,14 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::errors::ErrorCode;
 
-//--------------  Macro Rules  -------------------
+pub mod text_policy;
+pub mod uri_policy;
+pub mod access_control;
 
-#[macro_export]
-macro_rules! validate_no_emojis {
-    ($input:expr) => {
-        require!(!contains_emoji($input), ErrorCode::EmojisNotAllowed);
-    };
-}
+pub use text_policy::*;
+pub use uri_policy::*;
+pub use access_control::*;
+
+//--------------  Macro Rules  -------------------
 
 #[macro_export]
 macro_rules! check_user_achievement {
@@ -32,6 +32,12 @@ macro_rules! check_user_achievement {
                     ErrorCode::InvalidAchievement
                 );
             }
+            "helpful" => {
+                require!(
+                    $user_account.helpful_votes >= $record,
+                    ErrorCode::InvalidAchievement
+                );
+            }
             _ => {
                 return Err(ErrorCode::UnknownBadge.into());
             }
@@ -41,15 +47,11 @@ macro_rules! check_user_achievement {
 
 // -------------  Helper functions ---------------
 
-pub fn update_field(field: &mut String, new_value: Option<String>, max_len: usize) -> Result<()> {
-    match new_value {
-        Some(value) => {
-            require!(value.len() < max_len, ErrorCode::InvalidFieldLength);
-            require!(!value.is_empty(), ErrorCode::FieldIsEmpty);
+pub fn update_field(field: &mut String, new_value: Option<String>, rule: &TextRule) -> Result<()> {
+    if let Some(value) = new_value {
+        validate_text(&value, rule)?;
 
-            *field = value;
-        }
-        None => {}
+        *field = value;
     }
     Ok(())
 }
@@ -64,41 +66,13 @@ pub fn update_numeric_field<T: Copy>(field: &mut T, new_value: Option<T>) -> Res
     Ok(())
 }
 
-pub fn contains_emoji(input: &str) -> bool {
-    input.chars().any(|c| {
-        let c = c as u32;
-        (c >= 0x1f600 && c <= 0x1f64f) || // Emoticons
-            (c >= 0x1f300 && c <= 0x1f5ff) || // Misc Symbols & Pictographs
-            (c >= 0x1f680 && c <= 0x1f6ff) || // Transport & Map
-            (c >= 0x1f700 && c <= 0x1f77f) || // Alchemical Symbols
-            (c >= 0x1f780 && c <= 0x1f7ff) || // Geometric Shapes Extended
-            (c >= 0x1f800 && c <= 0x1f8ff) || // Supplemental Arrows-C
-            (c >= 0x1f900 && c <= 0x1f9ff) || // Supplemental Symbols & Pictographs
-            (c >= 0x1fa00 && c <= 0x1fa6f) || // Chess Symbols
-            (c >= 0x1fa70 && c <= 0x1faff) || // Symbols and Pictographs Extended-A
-            (c >= 0x2600 && c <= 0x26ff) || // Miscellaneous Symbols
-            (c >= 0x2700 && c <= 0x27bf) || // Dingbats
-            (c >= 0x2300 && c <= 0x23ff) || // Misc Technical
-            c == 0x2b50 || // Star
-            c == 0x3030 || // Wavy Dash
-            c == 0x2b06 || // Up Arrow
-            c == 0x2194 || // Left-Right Arrow
-            c == 0x1f004 || // Mahjong Tile
-            c == 0x1f0cf || // Joker
-            c == 0x1f171 || // Negative Squared AB
-            c == 0x1f18e || // Negative Squared NG
-            (c >= 0x1f191 && c <= 0x1f19a) || // Squared Latin Letters
-            (c >= 0x1f1e6 && c <= 0x1f1ff) || // Regional Indicator Symbols
-            (c >= 0x24c2 && c <= 0x1f251) // Enclosed Characters
-    })
-}
-
 // --------------- Helper Structs ------------------
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct EditUserParams {
     pub name: Option<String>,
     pub title: Option<String>,
+    pub affiliation: Option<String>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -126,7 +100,7 @@ pub struct EditPaperParams {
 
 // --------------------- ENUMS ----------------------
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
 pub enum Verdict {
     Approved,
     Rejected,
@@ -136,3 +110,80 @@ pub enum Verdict {
 impl Space for Verdict {
     const INIT_SPACE: usize = 1; // 1 byte is enough for an enum with <= 256 variants
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ReportCategory {
+    Plagiarism,
+    IllegalContent,
+    Spam,
+    Other,
+    AbusiveReview,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ReportStatus {
+    Open,
+    Dismissed,
+    Upheld,
+    Overturned,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum SubmissionStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum InvitationStatus {
+    Pending,
+    Completed,
+    Expired,
+    Submitted, // Sealed until the decision is published
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum AppealStatus {
+    Open,
+    Upheld,
+    Overturned,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum DisputeCategory {
+    Priority,
+    Plagiarism,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Dismissed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub enum ModerationStatus {
+    #[default]
+    Active,
+    TakenDown,
+    Appealed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum Role {
+    SuperAdmin,
+    FeeManager,
+    Treasurer,
+    Moderator,
+    Editor,
+    BadgeIssuer,
+    Arbitrator,
+}
+
+impl Role {
+    pub fn mask(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
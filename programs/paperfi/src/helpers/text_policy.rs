@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+//Text rules for every user provided string stored on chain.
+//Lengths are checked in characters (what the user sees) and in bytes (what the account can hold),
+//so a CJK or Hangul title is not cut at a third of the length of a latin one.
+pub struct TextRule {
+    pub max_chars: usize,
+    pub max_bytes: usize,
+}
+
+pub const USER_NAME: TextRule = TextRule { max_chars: 48, max_bytes: 144 };
+pub const USER_TITLE: TextRule = TextRule { max_chars: 32, max_bytes: 96 };
+pub const USER_AFFILIATION: TextRule = TextRule { max_chars: 64, max_bytes: 192 };
+pub const PAPER_INFO_URL: TextRule = TextRule { max_chars: 200, max_bytes: 200 };
+pub const PAPER_URI: TextRule = TextRule { max_chars: 200, max_bytes: 200 };
+pub const REVIEW_URI: TextRule = TextRule { max_chars: 200, max_bytes: 200 };
+pub const EVIDENCE_URI: TextRule = TextRule { max_chars: 200, max_bytes: 200 };
+pub const BOARD_NAME: TextRule = TextRule { max_chars: 32, max_bytes: 32 }; // used as a seed
+pub const JOURNAL_NAME: TextRule = TextRule { max_chars: 32, max_bytes: 32 }; // used as a seed
+pub const POOL_NAME: TextRule = TextRule { max_chars: 32, max_bytes: 32 }; // used as a seed
+pub const CONFLICT_DISCLOSURE: TextRule = TextRule { max_chars: 100, max_bytes: 300 };
+
+//Scripts and symbol blocks accepted in names, titles and metadata
+const ALLOWED_RANGES: &[(u32, u32)] = &[
+    (0x0020, 0x007e), // Basic Latin (printable)
+    (0x00a0, 0x024f), // Latin-1 Supplement, Latin Extended-A/B
+    (0x0250, 0x02ff), // IPA Extensions, Spacing Modifier Letters
+    (0x0300, 0x036f), // Combining Diacritical Marks
+    (0x0370, 0x03ff), // Greek and Coptic
+    (0x0400, 0x052f), // Cyrillic, Cyrillic Supplement
+    (0x0530, 0x058f), // Armenian
+    (0x0590, 0x05ff), // Hebrew
+    (0x0600, 0x06ff), // Arabic
+    (0x0750, 0x077f), // Arabic Supplement
+    (0x0900, 0x0dff), // Devanagari to Sinhala (Indic scripts)
+    (0x0e00, 0x0eff), // Thai, Lao
+    (0x10a0, 0x10ff), // Georgian
+    (0x1100, 0x11ff), // Hangul Jamo
+    (0x1e00, 0x1eff), // Latin Extended Additional
+    (0x1f00, 0x1fff), // Greek Extended
+    (0x2000, 0x206f), // General Punctuation
+    (0x2070, 0x209f), // Superscripts and Subscripts
+    (0x20a0, 0x20cf), // Currency Symbols
+    (0x2100, 0x218f), // Letterlike Symbols, Number Forms
+    (0x2200, 0x22ff), // Mathematical Operators
+    (0x3000, 0x303f), // CJK Symbols and Punctuation
+    (0x3040, 0x30ff), // Hiragana, Katakana
+    (0x3130, 0x318f), // Hangul Compatibility Jamo
+    (0x3400, 0x4dbf), // CJK Unified Ideographs Extension A
+    (0x4e00, 0x9fff), // CJK Unified Ideographs
+    (0xac00, 0xd7af), // Hangul Syllables
+    (0xf900, 0xfaff), // CJK Compatibility Ideographs
+    (0xff00, 0xffef), // Halfwidth and Fullwidth Forms
+    (0x20000, 0x2fa1f), // CJK Extensions B to F and Compatibility Supplement
+];
+
+//Emoji and pictographic blocks, rejected with their own error so clients can tell the user why
+const EMOJI_RANGES: &[(u32, u32)] = &[
+    (0x2300, 0x23ff), // Misc Technical
+    (0x2600, 0x27bf), // Miscellaneous Symbols, Dingbats
+    (0x2b00, 0x2bff), // Misc Symbols and Arrows
+    (0xfe00, 0xfe0f), // Variation Selectors (emoji presentation)
+    (0x1f000, 0x1faff), // Mahjong to Symbols and Pictographs Extended-A
+];
+
+//Characters that can reorder or hide the text around them
+const BIDI_CONTROLS: &[(u32, u32)] = &[
+    (0x061c, 0x061c), // Arabic Letter Mark
+    (0x200e, 0x200f), // LRM, RLM
+    (0x202a, 0x202e), // LRE, RLE, PDF, LRO, RLO
+    (0x2066, 0x2069), // LRI, RLI, FSI, PDI
+];
+
+//Invisible format characters (Unicode Cf) and separators inside the allowed ranges,
+//not caught by char::is_control
+const INVISIBLE_CHARS: &[(u32, u32)] = &[
+    (0x00ad, 0x00ad), // Soft Hyphen
+    (0x200b, 0x200d), // Zero Width Space, Non-Joiner, Joiner
+    (0x2028, 0x2029), // Line and Paragraph Separators
+    (0x2060, 0x2064), // Word Joiner, invisible math operators
+    (0x206a, 0x206f), // Deprecated format characters
+    (0xfeff, 0xfeff), // Zero Width No-Break Space (BOM)
+];
+
+fn in_ranges(c: u32, ranges: &[(u32, u32)]) -> bool {
+    ranges.iter().any(|(start, end)| (*start..=*end).contains(&c))
+}
+
+pub fn char_count(input: &str) -> usize {
+    input.chars().count()
+}
+
+pub fn validate_text(input: &str, rule: &TextRule) -> Result<()> {
+    require!(!input.is_empty(), ErrorCode::FieldIsEmpty);
+    require!(input.len() <= rule.max_bytes, ErrorCode::InvalidFieldLength);
+    require!(char_count(input) <= rule.max_chars, ErrorCode::InvalidFieldLength);
+
+    for c in input.chars() {
+        let code = c as u32;
+
+        require!(!c.is_control() && !in_ranges(code, INVISIBLE_CHARS), ErrorCode::ControlCharacterNotAllowed);
+        require!(!in_ranges(code, BIDI_CONTROLS), ErrorCode::BidiOverrideNotAllowed);
+        require!(!in_ranges(code, EMOJI_RANGES), ErrorCode::EmojisNotAllowed);
+        require!(in_ranges(code, ALLOWED_RANGES), ErrorCode::UnsupportedScript);
+    }
+
+    Ok(())
+}
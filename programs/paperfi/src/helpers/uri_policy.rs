@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::UriPolicy;
+
+pub struct ParsedUri<'a> {
+    pub scheme: &'a str,
+    pub host: &'a str,
+    pub path: &'a str,
+}
+
+//Minimal scheme://host/path parser, enough to check storage locations without pulling a url crate on chain
+pub fn parse_uri(uri: &str) -> Result<ParsedUri<'_>> {
+    require!(!uri.is_empty(), ErrorCode::FieldIsEmpty);
+    require!(!uri.chars().any(|c| c.is_whitespace()), ErrorCode::UriWhitespace);
+
+    let (scheme, rest) = uri.split_once("://").ok_or(ErrorCode::MalformedUri)?;
+
+    //scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+    let mut scheme_chars = scheme.chars();
+    require!(
+        scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic()),
+        ErrorCode::MalformedUri
+    );
+    require!(
+        scheme_chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'),
+        ErrorCode::MalformedUri
+    );
+
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+
+    //userinfo is rejected, "https://approved.host@other.host" would otherwise pass as the approved host
+    require!(!authority.contains('@'), ErrorCode::MalformedUri);
+
+    let host = match authority.split_once(':') {
+        Some((host, port)) => {
+            require!(
+                !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()),
+                ErrorCode::MalformedUri
+            );
+            host
+        }
+        None => authority,
+    };
+    require!(!host.is_empty(), ErrorCode::MalformedUri);
+
+    Ok(ParsedUri { scheme, host, path })
+}
+
+pub fn validate_uri(uri: &str, policy: &UriPolicy) -> Result<()> {
+    let parsed = parse_uri(uri)?;
+
+    //An empty policy only enforces well formed URIs
+    if policy.rules.is_empty() {
+        return Ok(());
+    }
+
+    let scheme_rules = policy.rules
+        .iter()
+        .filter(|rule| rule.scheme.eq_ignore_ascii_case(parsed.scheme));
+
+    let mut scheme_allowed = false;
+    for rule in scheme_rules {
+        scheme_allowed = true;
+        match &rule.host {
+            Some(host) if !host.eq_ignore_ascii_case(parsed.host) => {}
+            _ => {
+                return Ok(());
+            }
+        }
+    }
+
+    require!(scheme_allowed, ErrorCode::UriSchemeNotAllowed);
+    err!(ErrorCode::UriHostNotAllowed)
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, Review, ReviewResponse, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct AcknowledgeResponse<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+)]
+    pub paper: Account<'info, Paper>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(
+        mut,
+        seeds = [b"response", review.key().as_ref()],
+        bump = response.bump
+    )]
+    pub response: Account<'info, ReviewResponse>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcknowledgeResponse<'info> {
+    pub fn acknowledge_response(&mut self, _id: u64, verdict: Option<Verdict>) -> Result<()> {
+        require!(!self.response.acknowledged, ErrorCode::ResponseAlreadyAcknowledged);
+
+        let time = Clock::get()?.unix_timestamp as u64;
+        self.response.acknowledged = true;
+        self.response.acknowledged_at = time;
+
+        //A new verdict goes through the same path as edit_review
+        if let Some(verdict) = verdict {
+            require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+            require!(self.review.paper_version == self.paper.version, ErrorCode::ReviewVersionStale);
+
+            let old_verdict = self.review.change_verdict(verdict, time)?;
+            self.paper.apply_verdict_change(
+                &old_verdict,
+                &verdict,
+                self.review.weight,
+                &self.config.review_policy
+            )?;
+            self.paper.timestamp = time;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::PaperFiConfig;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct AddAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+}
+
+impl<'info> AddAdmin<'info> {
+    pub fn add_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        let config = &mut self.config;
+
+        //Only a config admin can add another one, roles can not promote themselves
+        require!(config.admins.contains(&self.admin.key()), ErrorCode::Unauthorized);
+
+        // Enforce max 3 admins
+        require!(config.admins.len() < PaperFiConfig::MAX_ADMINS, ErrorCode::TooManyAdmins);
+
+        // Check if the admin is already in the list
+        require!(!config.admins.contains(&new_admin), ErrorCode::AdminAlreadyExists);
+
+        config.admins.push(new_admin);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ EditorialBoard, BoardMember };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddBoardMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        seeds = [b"board", authority.key().as_ref(), board.name.as_bytes()],
+        bump = board.bump
+    )]
+    pub board: Account<'info, EditorialBoard>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BoardMember::INIT_SPACE,
+        seeds = [b"board_member", board.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub board_member: Account<'info, BoardMember>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddBoardMember<'info> {
+    pub fn add_board_member(&mut self, member: Pubkey, bump: u8) -> Result<()> {
+        self.board_member.set_inner(BoardMember {
+            board: self.board.key(),
+            member,
+            added_at: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        self.board.members = self.board.members.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+}
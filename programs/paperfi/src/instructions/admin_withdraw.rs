@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{ transfer, Transfer };
 use crate::errors::ErrorCode;
-use crate::state::{ PaperFiConfig };
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::helpers::*;
 
 #[derive(Accounts)]
 pub struct AdminWithdraw<'info> {
@@ -14,12 +15,15 @@ pub struct AdminWithdraw<'info> {
     #[account(seeds = [b"paperfi_config"], bump = config.bump)]
     pub config: Account<'info, PaperFiConfig>,
 
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> AdminWithdraw<'info> {
     pub fn admin_withdraw(&mut self) -> Result<()> {
-        require!(self.config.admins.contains(&self.admin.key()), ErrorCode::Unauthorized);
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::Treasurer)?;
 
         let vault_balance = self.config_vault.lamports();
 
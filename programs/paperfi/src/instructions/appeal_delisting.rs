@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Appeal, Journal, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct AppealDelisting<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ ErrorCode::Unauthorized,
+        seeds = [b"paper", owner.key().as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Journal that accepted the paper, its editorial board joins the moderators on the panel
+    pub journal: Option<Box<Account<'info, Journal>>>,
+
+    //One appeal per version, a new version starts from a fresh tally anyway
+    #[account(
+        init,
+        payer = owner,
+        space = Appeal::INIT_SPACE,
+        seeds = [b"appeal", paper.key().as_ref(), paper.version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub appeal: Box<Account<'info, Appeal>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AppealDelisting<'info> {
+    pub fn appeal_delisting(&mut self, _id: u64, rationale_uri: String, bump: u8) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        require!(self.paper.delisted_by_reviews, ErrorCode::NotDelistedByReviews);
+
+        validate_text(&rationale_uri, &EVIDENCE_URI)?;
+        validate_uri(&rationale_uri, &self.config.uri_policy)?;
+
+        let panel = match &self.journal {
+            Some(journal) => {
+                require!(self.paper.journal == Some(journal.key()), ErrorCode::Unauthorized);
+                Some(journal.board)
+            }
+            None => None,
+        };
+
+        self.appeal.set_inner(Appeal {
+            paper: self.paper.key(),
+            paper_version: self.paper.version,
+            appellant: self.owner.key(),
+            rationale_uri,
+            panel,
+            votes: Vec::new(),
+            status: AppealStatus::Open,
+            opened_at: Clock::get()?.unix_timestamp as u64,
+            decided_at: 0,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct AppealTakedown<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", owner.key().as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AppealTakedown<'info> {
+    pub fn appeal_takedown(&mut self, _id: u64, appeal_uri: String) -> Result<()> {
+        require!(
+            self.paper.moderation.status == ModerationStatus::TakenDown,
+            ErrorCode::PaperNotTakenDown
+        );
+        require!(
+            (Clock::get()?.unix_timestamp as u64) <= self.paper.moderation.appeal_deadline,
+            ErrorCode::AppealWindowClosed
+        );
+
+        validate_text(&appeal_uri, &EVIDENCE_URI)?;
+        validate_uri(&appeal_uri, &self.config.uri_policy)?;
+
+        let moderation = &mut self.paper.moderation;
+        moderation.status = ModerationStatus::Appealed;
+        moderation.appeal_uri = Some(appeal_uri);
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{ transfer, Transfer };
 use crate::state::{ Paper, UserAccount, PaperOwned, PaperFiConfig };
 use crate::errors::ErrorCode;
+use crate::constants::NATIVE_CURRENCY;
 
 #[derive(Accounts)]
 #[instruction(_id: u64)]
@@ -16,10 +17,11 @@ pub struct BuyPaper<'info> {
     )]
     pub buyer_user_account: Box<Account<'info, UserAccount>>, //already init, to use the platform must signup therefore user already exists
 
-    #[account(seeds = [b"user", paper.owner.as_ref()], bump = user_account.bump)]
-    pub user_account: Box<Account<'info, UserAccount>>, //paper owner user account
+    //paper owner user account, or the one of the wallet a dispute ruling redirected the proceeds to
+    #[account(seeds = [b"user", paper.payee().as_ref()], bump = user_account.bump)]
+    pub user_account: Box<Account<'info, UserAccount>>,
 
-    #[account(mut, seeds = [b"user_vault", paper.owner.as_ref()], bump = user_account.vault_bump)]
+    #[account(mut, seeds = [b"user_vault", paper.payee().as_ref()], bump = user_account.vault_bump)]
     pub user_vault: SystemAccount<'info>,
 
     #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
@@ -55,18 +57,25 @@ impl<'info> BuyPaper<'info> {
     pub fn buy_paper(&mut self, _id: u64, bump: u8) -> Result<()> {
         //Publishers already own the papers
         require!(self.buyer.key() != self.paper.owner, ErrorCode::PublisherCantBuy);
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        let now = Clock::get()?.unix_timestamp as u64;
 
         //create PaperOwned (proof of purchase)
         self.paper_owned.set_inner(PaperOwned {
             buyer: self.buyer.key(),
             paper: self.paper.key(),
-            timestamp: Clock::get()?.unix_timestamp as u64,
+            timestamp: now,
+            paid: 0,
             bump,
         });
 
         //Check if buyer is an author
         let is_author: bool = !self.author_pda.to_account_info().data_is_empty();
 
+        let mut revenue: u64 = 0;
+        let mut fee_amount: u64 = 0;
+
         //Check if the paper has a price and not author
         if self.paper.price > 0 && !is_author {
             let cpi_program = self.system_program.to_account_info();
@@ -77,31 +86,61 @@ impl<'info> BuyPaper<'info> {
 
             let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-            //Pay the paper owner
+            //Pay the paper owner or the dispute claimant
             transfer(cpi_ctx, self.paper.price)?;
+            revenue = self.paper.price;
 
             //get fee percentage
             let fee_percentage = self.config.fee.unwrap_or(0) as u64;
 
-            //Calculate price of fees and transfer fee from buyer to admin vault
-            let total_amout = self.paper.price
-                .checked_mul(100u64 + fee_percentage)
+            //The fee is a percentage of the price, charged to the buyer on top of it
+            fee_amount = self.paper.price
+                .checked_mul(fee_percentage)
+                .ok_or(ErrorCode::MathOverflow)?
+                .checked_div(100)
                 .ok_or(ErrorCode::MathOverflow)?;
 
-            let fee_amount = total_amout.checked_div(100).ok_or(ErrorCode::MathOverflow)?;
-
-            let cpi_accounts_2 = Transfer {
-                from: self.buyer.to_account_info(),
-                to: self.config_vault.to_account_info(),
+            //Part of the fee goes to the reviewers of the paper, kept in the paper account
+            let reviewer_share = if self.paper.reviews > 0 {
+                fee_amount
+                    .checked_mul(self.config.reviewer_fee_share_bps as u64)
+                    .ok_or(ErrorCode::MathOverflow)? / 10_000
+            } else {
+                0
             };
-
-            let cpi_ctx_2 = CpiContext::new(self.system_program.to_account_info(), cpi_accounts_2);
-            //Pays the fees to PaperFi
-            transfer(cpi_ctx_2, fee_amount)?;
+            let platform_fee = fee_amount - reviewer_share;
+
+            if reviewer_share > 0 {
+                let cpi_accounts = Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: self.paper.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+                transfer(cpi_ctx, reviewer_share)?;
+
+                let reviews = self.paper.reviews;
+                self.paper.reward_pool.deposit(reviewer_share, reviews)?;
+            }
+
+            if platform_fee > 0 {
+                let cpi_accounts_2 = Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: self.config_vault.to_account_info(),
+                };
+
+                let cpi_ctx_2 = CpiContext::new(
+                    self.system_program.to_account_info(),
+                    cpi_accounts_2
+                );
+                //Pays the fees to PaperFi
+                transfer(cpi_ctx_2, platform_fee)?;
+            }
         }
 
         //register sales in the paper state
+        self.paper_owned.paid = revenue;
         self.paper.sales += 1;
+        self.paper.sales_stats.record_sale(NATIVE_CURRENCY, revenue, fee_amount, now)?;
 
         //register purchase in the buyer user_account state
         self.buyer_user_account.purchases += 1;
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, Review };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ClaimReviewRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+)]
+    pub paper: Account<'info, Paper>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimReviewRewards<'info> {
+    pub fn claim_review_rewards(&mut self, _id: u64) -> Result<()> {
+        let pool = &mut self.paper.reward_pool;
+        let amount = pool.pending(self.review.reward_debt);
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        //Claims are tracked on the review so the same fees are never paid twice
+        self.review.reward_debt = pool.acc_per_review;
+        self.review.rewards_claimed = self.review.rewards_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        //rewarded reviews can't be withdrawn
+        self.review.rewarded = true;
+        pool.claimed = pool.claimed.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+        //The pool lamports sit on top of the paper rent, the program owns the account so it can move them
+        **self.paper.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.signer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        Ok(())
+    }
+}
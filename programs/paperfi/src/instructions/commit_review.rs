@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+
+use crate::state::{
+    UserAccount,
+    Paper,
+    PaperOwned,
+    ReviewCommitment,
+    ConflictList,
+    BoardMember,
+    PaperFiConfig,
+    ReviewerStake,
+};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct CommitReview<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"user", signer.key().as_ref()], bump = reviewer_user_account.bump)]
+    pub reviewer_user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(seeds = [b"author", signer.key().as_ref(), paper.key().as_ref()], bump)]
+    /// CHECKED : Must be empty, authors can't review their own papers
+    pub paper_author: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"purchase", signer.key().as_ref(), paper.key().as_ref()],
+        bump = paper_owned.bump
+    )]
+    pub paper_owned: Box<Account<'info, PaperOwned>>,
+
+    #[account(seeds = [b"conflicts", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ConflictList::check when the authors created it
+    pub conflicts: UncheckedAccount<'info>,
+
+    //Proofs for the reviewer requirements, only needed when the paper or platform requires them
+    pub badge_asset: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    //Only needed when the platform requires a stake
+    #[account(seeds = [b"stake", signer.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Box<Account<'info, ReviewerStake>>>,
+
+    #[account(
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            paper.version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    /// CHECKED : Must be empty, one review per reviewer
+    pub review: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ReviewCommitment::INIT_SPACE,
+        seeds = [b"commitment", signer.key().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, ReviewCommitment>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitReview<'info> {
+    pub fn commit_review(&mut self, _id: u64, commitment: [u8; 32], bump: u8) -> Result<()> {
+        //Same reviewer rules as review_paper
+        require!(self.paper.owner.key() != self.signer.key(), ErrorCode::Unauthorized);
+        require!(self.paper_author.to_account_info().data_is_empty(), ErrorCode::Unauthorized);
+        require!(self.review.to_account_info().data_is_empty(), ErrorCode::Unauthorized);
+        ConflictList::check(&self.conflicts, &self.reviewer_user_account)?;
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        let time = Clock::get()?.unix_timestamp as u64;
+        //eligibility set by the platform and by the owner
+        self.paper.check_reviewer(
+            &self.config.reviewer_requirements,
+            &self.reviewer_user_account,
+            time,
+            self.badge_asset.as_deref().map(|asset| &**asset),
+            self.board_member.as_deref().map(|member| &**member)
+        )?;
+        ReviewerStake::check(self.stake.as_deref().map(|stake| &**stake), self.config.min_reviewer_stake)?;
+        let round = self.paper.review_round.as_mut().ok_or(ErrorCode::NoReviewRound)?;
+        require!(time < round.commit_deadline, ErrorCode::CommitPhaseClosed);
+        round.commitments = round.commitments.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        let reveal_deadline = round.reveal_deadline;
+
+        self.commitment.set_inner(ReviewCommitment {
+            reviewer: self.signer.key(),
+            paper: self.paper.key(),
+            commitment,
+            reveal_deadline,
+            timestamp: time,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::EditorialBoard;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateBoard<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EditorialBoard::INIT_SPACE,
+        seeds = [b"board", authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub board: Account<'info, EditorialBoard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateBoard<'info> {
+    pub fn create_board(&mut self, name: String, bump: u8) -> Result<()> {
+        validate_text(&name, &BOARD_NAME)?;
+
+        self.board.set_inner(EditorialBoard {
+            authority: self.authority.key(),
+            name,
+            members: 0,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        Ok(())
+    }
+}
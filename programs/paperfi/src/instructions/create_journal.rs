@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Journal, EditorialBoard };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateJournal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    //Editors of the journal
+    #[account(
+        has_one = authority @ ErrorCode::Unauthorized,
+        seeds = [b"board", authority.key().as_ref(), board.name.as_bytes()],
+        bump = board.bump
+    )]
+    pub board: Account<'info, EditorialBoard>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Journal::INIT_SPACE,
+        seeds = [b"journal", authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub journal: Account<'info, Journal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateJournal<'info> {
+    pub fn create_journal(&mut self, name: String, bump: u8) -> Result<()> {
+        validate_text(&name, &JOURNAL_NAME)?;
+
+        self.journal.set_inner(Journal {
+            authority: self.authority.key(),
+            name,
+            board: self.board.key(),
+            submissions: 0,
+            accepted: 0,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        Ok(())
+    }
+}
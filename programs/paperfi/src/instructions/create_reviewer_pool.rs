@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ ReviewerPool, Journal, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateReviewerPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    //Pools of a journal are run by its authority, field pools by editors
+    pub journal: Option<Account<'info, Journal>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", authority.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ReviewerPool::INIT_SPACE,
+        seeds = [b"reviewer_pool", authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub pool: Account<'info, ReviewerPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateReviewerPool<'info> {
+    pub fn create_reviewer_pool(&mut self, name: String, bump: u8) -> Result<()> {
+        validate_text(&name, &POOL_NAME)?;
+
+        let journal = match &self.journal {
+            Some(journal) => {
+                require!(journal.authority == self.authority.key(), ErrorCode::Unauthorized);
+                Some(journal.key())
+            }
+            //field pools share one draw per paper, only editors run them
+            None => {
+                authorize(&self.config, &self.authority.key(), self.role_grant.as_deref(), Role::Editor)?;
+                None
+            }
+        };
+
+        self.pool.set_inner(ReviewerPool {
+            authority: self.authority.key(),
+            name,
+            journal,
+            reviewers: Vec::new(),
+            draws: 0,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        Ok(())
+    }
+}
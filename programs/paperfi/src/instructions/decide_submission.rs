@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, Journal, Submission, BoardMember, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct DecideSubmission<'info> {
+    #[account(mut)]
+    pub editor: Signer<'info>,
+
+    #[account(mut, seeds = [b"journal", journal.authority.as_ref(), journal.name.as_bytes()], bump = journal.bump)]
+    pub journal: Box<Account<'info, Journal>>,
+
+    //Not needed by the journal authority
+    #[account(seeds = [b"board_member", journal.board.as_ref(), editor.key().as_ref()], bump = board_member.bump)]
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    #[account(
+        mut,
+        has_one = journal @ ErrorCode::Unauthorized,
+        seeds = [b"submission", journal.key().as_ref(), paper.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Box<Account<'info, Submission>>,
+
+    #[account(mut, address = submission.paper)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Must be empty, authors can't decide on their own paper
+    #[account(seeds = [b"author", editor.key().as_ref(), paper.key().as_ref()], bump)]
+    /// CHECK: only checked for data
+    pub paper_author: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DecideSubmission<'info> {
+    //Editorial decision, kept apart from the open review tally
+    pub fn decide_submission(&mut self, accept: bool, decision_uri: Option<String>) -> Result<()> {
+        require!(
+            self.editor.key() == self.journal.authority || self.board_member.is_some(),
+            ErrorCode::NotJournalEditor
+        );
+        require!(self.submission.status == SubmissionStatus::Pending, ErrorCode::SubmissionDecided);
+        //owners and co-authors can't accept their own paper, even when they run the journal
+        require!(
+            self.editor.key() != self.paper.owner && self.paper_author.data_is_empty(),
+            ErrorCode::EditorIsAuthor
+        );
+        //the decision is on the version that was submitted
+        require!(self.submission.paper_version == self.paper.version, ErrorCode::StaleSubmission);
+
+        if let Some(uri) = &decision_uri {
+            validate_text(uri, &REVIEW_URI)?;
+            validate_uri(uri, &self.config.uri_policy)?;
+        }
+
+        let submission = &mut self.submission;
+        submission.editor = Some(self.editor.key());
+        submission.decision_uri = decision_uri;
+        submission.decided_at = Clock::get()?.unix_timestamp as u64;
+
+        if accept {
+            //the paper may have been accepted by another journal in the meantime
+            require!(self.paper.journal.is_none(), ErrorCode::AlreadyInJournal);
+            submission.status = SubmissionStatus::Accepted;
+            self.paper.journal = Some(self.journal.key());
+            self.journal.accepted = self.journal.accepted.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        } else {
+            submission.status = SubmissionStatus::Rejected;
+        }
+
+        Ok(())
+    }
+}
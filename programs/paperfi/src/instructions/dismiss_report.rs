@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Report, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct DismissReport<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", moderator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"report", report.reporter.as_ref(), report.target().as_ref()],
+        bump = report.bump
+    )]
+    pub report: Account<'info, Report>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DismissReport<'info> {
+    pub fn dismiss_report(&mut self) -> Result<()> {
+        authorize(&self.config, &self.moderator.key(), self.role_grant.as_deref(), Role::Moderator)?;
+        require!(self.report.status == ReportStatus::Open, ErrorCode::ReportAlreadyResolved);
+
+        let report = &mut self.report;
+        report.status = ReportStatus::Dismissed;
+        report.moderator = Some(self.moderator.key());
+        report.resolved_at = Clock::get()?.unix_timestamp as u64;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ hash::hashv, sysvar::slot_hashes };
+use mpl_core::{ accounts::BaseAssetV1, types::UpdateAuthority };
+
+use crate::state::{
+    Paper,
+    ConflictList,
+    ReviewerPool,
+    ReviewerDraw,
+    Submission,
+    UserAccount,
+    ReviewerStake,
+    BoardMember,
+    PaperFiConfig,
+};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct DrawReviewers<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        seeds = [b"reviewer_pool", authority.key().as_ref(), pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, ReviewerPool>>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Journal pools only draw for papers submitted to the journal
+    #[account(
+        seeds = [b"submission", pool.journal.unwrap_or_default().as_ref(), paper.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Option<Box<Account<'info, Submission>>>,
+
+    #[account(seeds = [b"conflicts", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ConflictList::load when the authors created it
+    pub conflicts: UncheckedAccount<'info>,
+
+    //One draw per journal (or for all field pools) and paper, so another pool can't reroll it
+    #[account(
+        init,
+        payer = authority,
+        space = ReviewerDraw::INIT_SPACE,
+        seeds = [b"reviewer_draw", pool.journal.unwrap_or_default().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub draw: Box<Account<'info, ReviewerDraw>>,
+
+    #[account(address = slot_hashes::ID)]
+    /// CHECKED : Only the most recent entry is read, the sysvar is too large to deserialize
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DrawReviewers<'info> {
+    pub fn draw_reviewers(
+        &mut self,
+        _id: u64,
+        count: u8,
+        candidate_accounts: &[AccountInfo<'info>],
+        bump: u8
+    ) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        require!(
+            count > 0 && (count as usize) <= ReviewerDraw::MAX_DRAWN,
+            ErrorCode::InvalidDrawSize
+        );
+        require!(
+            self.pool.journal.is_none() || self.submission.is_some(),
+            ErrorCode::NotSubmittedToJournal
+        );
+
+        //only reviewers who could review the paper right now are drawn
+        let now = Clock::get()?.unix_timestamp as u64;
+        let conflicts = ConflictList::load(&self.conflicts)?;
+        let mut proofs = candidate_accounts.iter();
+        let mut candidates = Vec::with_capacity(self.pool.reviewers.len());
+        for reviewer in self.pool.reviewers.iter() {
+            if self.is_eligible(reviewer, &mut proofs, conflicts.as_ref(), now)? {
+                candidates.push(*reviewer);
+            }
+        }
+        require!(proofs.next().is_none(), ErrorCode::InvalidCandidateAccounts);
+        require!(candidates.len() >= (count as usize), ErrorCode::NotEnoughCandidates);
+
+        //layout: entries count (u64), then (slot u64, hash [u8; 32]) from the most recent
+        let (slot, slot_hash) = {
+            let data = self.slot_hashes.try_borrow_data()?;
+            require!(data.len() >= 48, ErrorCode::InvalidDrawSize);
+            let mut slot = [0u8; 8];
+            slot.copy_from_slice(&data[8..16]);
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&data[16..48]);
+            (u64::from_le_bytes(slot), hash)
+        };
+
+        let pool_key = self.pool.key();
+        let paper_key = self.paper.key();
+        let seed = hashv(&[&slot_hash, pool_key.as_ref(), paper_key.as_ref()]).to_bytes();
+        let candidate_count = candidates.len() as u32;
+        let reviewers = ReviewerDraw::pick(&seed, candidates, count as usize);
+
+        self.draw.set_inner(ReviewerDraw {
+            pool: pool_key,
+            paper: paper_key,
+            drawn_by: self.authority.key(),
+            slot,
+            seed,
+            candidates: candidate_count,
+            reviewers,
+            timestamp: now,
+            bump,
+        });
+
+        self.pool.draws = self.pool.draws.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+
+    //Same rules as ReviewPaper: not an author, not in the conflict list, signed up, staked and meeting the requirements.
+    //Proofs are passed per pool reviewer, in pool order: user account and author PDA, then the stake PDA when the
+    //platform requires a stake, the badge asset when a badge is required and the board member PDA when a board is.
+    //Every proof is bound to the reviewer, so the pool authority can't leave an eligible reviewer out
+    fn is_eligible<'a>(
+        &self,
+        reviewer: &Pubkey,
+        proofs: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+        conflicts: Option<&ConflictList>,
+        now: u64
+    ) -> Result<bool>
+        where 'info: 'a
+    {
+        let paper_key = self.paper.key();
+        let platform = &self.config.reviewer_requirements;
+        let paper = self.paper.reviewer_requirements.as_ref();
+        let badge_collection = paper.and_then(|paper| paper.badge_collection).or(platform.badge_collection);
+        let editorial_board = paper.and_then(|paper| paper.editorial_board).or(platform.editorial_board);
+
+        let user_info = next_proof(proofs, &[b"user", reviewer.as_ref()])?;
+        let author_info = next_proof(proofs, &[b"author", reviewer.as_ref(), paper_key.as_ref()])?;
+        let stake_info = if self.config.min_reviewer_stake > 0 {
+            Some(next_proof(proofs, &[b"stake", reviewer.as_ref()])?)
+        } else {
+            None
+        };
+        let badge_info = match badge_collection {
+            Some(_) => Some(proofs.next().ok_or(ErrorCode::InvalidCandidateAccounts)?),
+            None => None,
+        };
+        let member_info = match editorial_board {
+            Some(board) => Some(next_proof(proofs, &[b"board_member", board.as_ref(), reviewer.as_ref()])?),
+            None => None,
+        };
+
+        if *reviewer == self.paper.owner || !author_info.data_is_empty() {
+            return Ok(false);
+        }
+        let Some(user) = load_proof::<UserAccount>(user_info)? else {
+            return Ok(false);
+        };
+        if conflicts.is_some_and(|list| list.excludes(&user)) {
+            return Ok(false);
+        }
+
+        //the badge asset can't be derived, it must be one the reviewer holds
+        let badge_asset = match (badge_info, badge_collection) {
+            (Some(info), Some(collection)) => {
+                let asset = load_proof::<BaseAssetV1>(info)?.ok_or(ErrorCode::InvalidCandidateAccounts)?;
+                require!(
+                    asset.owner == *reviewer &&
+                        asset.update_authority == UpdateAuthority::Collection(collection),
+                    ErrorCode::InvalidCandidateAccounts
+                );
+                Some(asset)
+            }
+            _ => None,
+        };
+
+        let stake = stake_info.map(load_proof::<ReviewerStake>).transpose()?.flatten();
+        let board_member = member_info.map(load_proof::<BoardMember>).transpose()?.flatten();
+
+        let meets_requirements = self.paper
+            .check_reviewer(platform, &user, now, badge_asset.as_ref(), board_member.as_ref())
+            .is_ok();
+        let staked = ReviewerStake::check(stake.as_ref(), self.config.min_reviewer_stake).is_ok();
+
+        Ok(meets_requirements && staked)
+    }
+}
+
+//Next proof account, which must be the PDA derived from the seeds
+fn next_proof<'a, 'info: 'a>(
+    proofs: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+    seeds: &[&[u8]]
+) -> Result<&'a AccountInfo<'info>> {
+    let info = proofs.next().ok_or(ErrorCode::InvalidCandidateAccounts)?;
+    require_keys_eq!(
+        info.key(),
+        Pubkey::find_program_address(seeds, &crate::ID).0,
+        ErrorCode::InvalidCandidateAccounts
+    );
+    Ok(info)
+}
+
+//Deserializes a proof account, an empty PDA means the reviewer has none
+fn load_proof<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(*info.owner, T::owner(), ErrorCode::InvalidCandidateAccounts);
+    Ok(Some(T::try_deserialize(&mut &info.data.borrow()[..])?))
+}
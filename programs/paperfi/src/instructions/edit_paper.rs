@@ -1,10 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{ Paper };
+use crate::state::{ Paper, PaperFiConfig, PaperVersion };
 use crate::helpers::*;
 use crate::errors::ErrorCode;
-use crate::{ validate_no_emojis };
-use crate::contains_emoji;
 
 #[derive(Accounts)]
 #[instruction(_id: u64)]
@@ -19,31 +17,97 @@ pub struct EditPaper<'info> {
 )]
     pub paper: Account<'info, Paper>,
 
+    //Only needed when publishing a new version, archives the tallies of the current one
+    #[account(
+        init,
+        payer = owner,
+        space = PaperVersion::INIT_SPACE,
+        seeds = [b"paper_version", paper.key().as_ref(), paper.version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub paper_version: Option<Account<'info, PaperVersion>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> EditPaper<'info> {
-    pub fn edit_paper(&mut self, _id: u64, params: EditPaperParams) -> Result<()> {
+    pub fn edit_paper(
+        &mut self,
+        _id: u64,
+        params: EditPaperParams,
+        version_bump: Option<u8>
+    ) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp as u64;
         let paper = &mut self.paper;
-        update_field(&mut paper.paper_info_url, params.paper_info_url, 200)?;
-        update_field(&mut paper.paper_uri, params.paper_uri, 200)?;
+        let new_version = params.version.filter(|version| *version != paper.version);
+
+        //Reviews judged the current content, new content is always a new version
+        let content_changed = params.paper_uri
+            .as_ref()
+            .is_some_and(|uri| *uri != paper.paper_uri);
+        require!(!content_changed || new_version.is_some(), ErrorCode::VersionBumpRequired);
+
+        //A new version starts a new review tally, old verdicts don't judge the new content
+        if let Some(version) = new_version {
+            require!(version > paper.version, ErrorCode::InvalidPaperVersion);
+            //commitments of an open round were made on the current content
+            require!(!paper.in_review_round(time), ErrorCode::ReviewRoundActive);
+
+            let (archive, bump) = self.paper_version
+                .as_mut()
+                .zip(version_bump)
+                .ok_or(ErrorCode::VersionArchiveMismatch)?;
+            archive.set_inner(PaperVersion {
+                paper: paper.key(),
+                version: paper.version,
+                paper_uri: paper.paper_uri.clone(),
+                reviews: paper.version_reviews,
+                review_status: paper.review_status.clone(),
+                score_summary: paper.score_summary.clone(),
+                archived_at: time,
+                bump,
+            });
+
+            paper.start_version(version, &self.config.review_policy);
+        } else {
+            //an archive created without a new version would block the real one
+            require!(self.paper_version.is_none(), ErrorCode::VersionArchiveMismatch);
+        }
+
+        //sales redirected by a dispute ruling can't be cut off by the owner
+        if paper.proceeds_recipient.is_some() {
+            require!(
+                params.price.unwrap_or(paper.price) == paper.price &&
+                    params.listed.unwrap_or(paper.listed) == paper.listed,
+                ErrorCode::ProceedsRedirected
+            );
+        }
+
+        update_field(&mut paper.paper_info_url, params.paper_info_url, &PAPER_INFO_URL)?;
+        update_field(&mut paper.paper_uri, params.paper_uri, &PAPER_URI)?;
         update_numeric_field(&mut paper.price, params.price)?;
-        update_numeric_field(&mut paper.version, params.version)?;
 
         //Since the fileds are optional lets make the requirement after the change (solana atomic)
         //if there was a change that doesn't respect the requirements tx will fail and change wont happen
         require!(paper.price == 0 || paper.price >= 1_000_000, ErrorCode::IncorrectPricing);
-        validate_no_emojis!(&paper.paper_info_url);
-        validate_no_emojis!(&paper.paper_uri);
+        validate_uri(&paper.paper_info_url, &self.config.uri_policy)?;
+        validate_uri(&paper.paper_uri, &self.config.uri_policy)?;
 
         match params.listed {
             Some(listed) => {
+                //Only moderators can bring back a paper that was taken down
+                require!(!listed || paper.is_active(), ErrorCode::PaperTakenDown);
+                //Papers unlisted by their reviews come back through the listing policy
+                require!(!listed || !paper.delisted_by_reviews, ErrorCode::DelistedByReviews);
                 paper.listed = listed;
             }
             None => {} // Do nothing if there's no new value
         }
 
-        paper.timestamp = Clock::get()?.unix_timestamp as u64;
+        paper.timestamp = time;
 
         Ok(())
     }
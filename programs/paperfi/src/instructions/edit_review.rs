@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{ Paper, Review };
+use crate::state::{ Paper, Review, PaperFiConfig };
 use crate::helpers::*;
+use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(_id: u64)]
@@ -15,32 +16,43 @@ pub struct EditReview<'info> {
 )]
     pub paper: Account<'info, Paper>,
 
-    #[account(mut, seeds = [b"review", signer.key().as_ref(), paper.key().as_ref()], bump)]
+    #[account(
+        mut,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
     pub review: Account<'info, Review>,
 
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> EditReview<'info> {
     pub fn edit_review(&mut self, _id: u64, verdict: Verdict) -> Result<()> {
-        // Check if the previous verdict was `ReviewRequested`
-        if self.review.verdict == Verdict::ReviewRequested {
-            // If previous verdict was `ReviewRequested`, decrement the count
-            self.paper.review_status.review_requested -= 1;
-        }
-
-        // Update the review verdict and timestamp
-        self.review.verdict = verdict.clone();
-        self.review.timestamp = Clock::get()?.unix_timestamp as u64;
-
-        //update Paper Review Status based on the new verdict
-        self.paper.review_status.update(&verdict);
-        self.paper.timestamp = Clock::get()?.unix_timestamp as u64;
-
-        // If the paper is approved and not already listed, mark it as listed
-        if verdict == Verdict::Approved && self.paper.listed == false {
-            self.paper.listed = true;
-        }
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        //only verdicts on the current version drive the listing
+        require!(self.review.paper_version == self.paper.version, ErrorCode::ReviewVersionStale);
+
+        let time = Clock::get()?.unix_timestamp as u64;
+
+        // Update the review verdict, keeping the previous one in the edit history
+        let old_verdict = self.review.change_verdict(verdict, time)?;
+
+        //Move the review between Review Status buckets and re-run the listing policy
+        self.paper.apply_verdict_change(
+            &old_verdict,
+            &verdict,
+            self.review.weight,
+            &self.config.review_policy
+        )?;
+        self.paper.timestamp = time;
 
         Ok(())
     }
@@ -20,10 +20,12 @@ pub struct EditUser<'info> {
 pub fn edit_user(ctx: Context<EditUser>, params: EditUserParams) -> Result<()> {
     let user = &mut ctx.accounts.user;
 
-    update_field(&mut user.name, params.name, 49)?;
-    update_field(&mut user.title, params.title, 33)?;
+    update_field(&mut user.name, params.name, &USER_NAME)?;
+    update_field(&mut user.title, params.title, &USER_TITLE)?;
+    let time = Clock::get()?.unix_timestamp as u64;
+    user.set_affiliation(params.affiliation, time)?;
 
-    user.timestamp = Clock::get()?.unix_timestamp as u64;
+    user.timestamp = time;
 
     Ok(())
 }
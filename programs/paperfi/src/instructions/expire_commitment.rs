@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, ReviewCommitment, UserAccount };
+use crate::errors::ErrorCode;
+
+//Anyone can clean up a commitment that was not revealed in time, rent goes back to the reviewer
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ExpireCommitment<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, address = commitment.reviewer)]
+    pub reviewer: SystemAccount<'info>,
+
+    #[account(mut, seeds = [b"user", reviewer.key().as_ref()], bump = reviewer_user_account.bump)]
+    pub reviewer_user_account: Account<'info, UserAccount>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Account<'info, Paper>,
+
+    #[account(
+        mut,
+        close = reviewer,
+        seeds = [b"commitment", reviewer.key().as_ref(), paper.key().as_ref()],
+        bump = commitment.bump
+    )]
+    pub commitment: Account<'info, ReviewCommitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExpireCommitment<'info> {
+    pub fn expire_commitment(&mut self, _id: u64) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp as u64;
+        require!(time >= self.commitment.reveal_deadline, ErrorCode::CommitmentNotExpired);
+
+        //only count it against the round it was made in
+        if let Some(round) = self.paper.review_round.as_mut() {
+            if round.reveal_deadline == self.commitment.reveal_deadline {
+                round.expired = round.expired.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+            }
+        }
+
+        //the miss stays on the reviewer profile
+        let user = &mut self.reviewer_user_account;
+        user.missed_reviews = user.missed_reviews.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+}
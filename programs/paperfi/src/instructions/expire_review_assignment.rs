@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, ReviewInvitation, ReviewBounty, UserAccount };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+//Anyone can expire an invitation past its deadline, or a sealed review nobody revealed in time.
+//The inviter can then reassign it
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ExpireReviewAssignment<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        has_one = paper @ ErrorCode::Unauthorized,
+        seeds = [b"invitation", paper.key().as_ref(), invitation.reviewer_commitment.as_ref()],
+        bump = invitation.bump
+    )]
+    pub invitation: Box<Account<'info, ReviewInvitation>>,
+
+    //Gives back the reserved payout, empty once the bounty was refunded
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ReviewBounty::load when it exists
+    pub bounty: UncheckedAccount<'info>,
+
+    //The reviewer is hidden, only someone knowing the salt can record the miss on the profile
+    #[account(mut)]
+    pub reviewer_user_account: Option<Box<Account<'info, UserAccount>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExpireReviewAssignment<'info> {
+    pub fn expire_review_assignment(&mut self, _id: u64, salt: Option<[u8; 32]>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        let invitation = &mut self.invitation;
+        let expires_at = match invitation.status {
+            InvitationStatus::Pending => invitation.deadline,
+            InvitationStatus::Submitted => invitation.reveal_deadline(),
+            _ => return Err(ErrorCode::InvitationNotPending.into()),
+        };
+        require!(now > expires_at, ErrorCode::InvitationNotExpired);
+
+        invitation.status = InvitationStatus::Expired;
+
+        //a reservation can't be left holding a slot of the bounty
+        if invitation.bounty_reserved {
+            invitation.bounty_reserved = false;
+            if let Some(mut bounty) = ReviewBounty::load(&self.bounty)? {
+                bounty.release();
+                bounty.store(&self.bounty)?;
+            }
+        }
+
+        if let (Some(user), Some(salt)) = (self.reviewer_user_account.as_mut(), salt) {
+            require!(
+                ReviewInvitation::hash(&user.owner, &salt) == invitation.reviewer_commitment,
+                ErrorCode::CommitmentMismatch
+            );
+            invitation.reviewer = Some(user.owner);
+            user.missed_reviews = user.missed_reviews.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        Ok(())
+    }
+}
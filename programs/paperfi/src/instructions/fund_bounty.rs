@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use anchor_spl::token::{ self, Mint, Token, TokenAccount };
+
+use crate::state::{ Paper, PaperAuthor, ReviewBounty };
+use crate::constants::MAX_BOUNTY_DURATION_SECONDS;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct FundBounty<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Only needed when the funder is a co-author and not the owner
+    #[account(
+        seeds = [b"author", funder.key().as_ref(), paper.key().as_ref()],
+        bump = paper_author.bump
+    )]
+    pub paper_author: Option<Account<'info, PaperAuthor>>,
+
+    #[account(
+        init,
+        payer = funder,
+        space = ReviewBounty::INIT_SPACE,
+        seeds = [b"bounty", paper.key().as_ref()],
+        bump
+    )]
+    pub bounty: Box<Account<'info, ReviewBounty>>,
+
+    //Native SOL escrow
+    #[account(mut, seeds = [b"bounty_vault", bounty.key().as_ref()], bump)]
+    pub bounty_vault: SystemAccount<'info>,
+
+    //SPL bounties only: the mint, the funder tokens and the token escrow owned by the bounty
+    pub mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(mut)]
+    pub funder_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = funder,
+        seeds = [b"bounty_token_vault", bounty.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = bounty
+    )]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundBounty<'info> {
+    pub fn fund_bounty(
+        &mut self,
+        _id: u64,
+        payout: u64,
+        max_reviews: u32,
+        duration: u64,
+        bumps: &FundBountyBumps
+    ) -> Result<()> {
+        let is_owner = self.paper.owner == self.funder.key();
+        let is_author = self.paper_author
+            .as_ref()
+            .is_some_and(|author| author.verify && author.author == self.funder.key());
+        require!(is_owner || is_author, ErrorCode::Unauthorized);
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        require!(payout > 0 && max_reviews > 0, ErrorCode::InvalidBounty);
+        require!((1..=MAX_BOUNTY_DURATION_SECONDS).contains(&duration), ErrorCode::InvalidBounty);
+        //what is left in a SOL vault must stay rent exempt after every payout
+        require!(
+            self.mint.is_some() || payout >= Rent::get()?.minimum_balance(0),
+            ErrorCode::InvalidBounty
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        self.bounty.set_inner(ReviewBounty {
+            paper: self.paper.key(),
+            funder: self.funder.key(),
+            mint: self.mint.as_ref().map(|mint| mint.key()),
+            payout,
+            max_reviews,
+            paid_reviews: 0,
+            reserved_reviews: 0,
+            expires_at: now + duration,
+            timestamp: now,
+            bump: bumps.bounty,
+            vault_bump: bumps.bounty_vault,
+        });
+
+        let amount = self.bounty.total().ok_or(ErrorCode::MathOverflow)?;
+
+        match (&self.mint, &self.funder_token_account, &self.bounty_token_vault, &self.token_program) {
+            (Some(_), Some(from), Some(to), Some(token_program)) => {
+                let cpi_accounts = token::Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.funder.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+                token::transfer(cpi_ctx, amount)?;
+            }
+            (None, None, None, None) => {
+                let cpi_accounts = Transfer {
+                    from: self.funder.to_account_info(),
+                    to: self.bounty_vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+                transfer(cpi_ctx, amount)?;
+            }
+            _ => {
+                return Err(ErrorCode::MissingTokenAccounts.into());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = admin_role.bump)]
+    pub admin_role: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RoleGrant::INIT_SPACE,
+        seeds = [b"role", wallet.as_ref()],
+        bump
+    )]
+    pub role_grant: Account<'info, RoleGrant>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantRole<'info> {
+    pub fn grant_role(&mut self, wallet: Pubkey, role: Role, bumps: &GrantRoleBumps) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.admin_role.as_deref(), Role::SuperAdmin)?;
+
+        let grant = &mut self.role_grant;
+        grant.wallet = wallet;
+        grant.roles |= role.mask();
+        grant.granted_by = self.admin.key();
+        grant.timestamp = Clock::get()?.unix_timestamp as u64;
+        grant.bump = bumps.role_grant;
+
+        Ok(())
+    }
+}
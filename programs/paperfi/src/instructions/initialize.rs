@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{ PaperFiConfig };
+use crate::state::{ PaperFiConfig, UriPolicy, ReviewPolicy, Dispute };
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -26,12 +26,8 @@ impl<'info> Initialize<'info> {
     pub fn generate_accounts(&mut self, bumps: InitializeBumps) -> Result<()> {
         let config = &mut self.config;
 
-        // Enforce max 3 admins
-        require!(config.admins.len() < PaperFiConfig::MAX_ADMINS, ErrorCode::TooManyAdmins);
-
-        // Check if the admin is already in the list
-        let is_admin_already_added = config.admins.iter().any(|admin| admin == &self.admin.key());
-        require!(!is_admin_already_added, ErrorCode::AdminAlreadyExists);
+        // Only the first admin bootstraps the config, the next ones are added by an admin
+        require!(config.admins.is_empty(), ErrorCode::AdminAlreadyExists);
 
         config.admins.push(self.admin.key());
 
@@ -40,6 +36,9 @@ impl<'info> Initialize<'info> {
             config.fee = Some(2); // 2% - for now this is fixed
             config.bump = bumps.config;
             config.vault_bump = bumps.config_vault;
+            config.uri_policy.set_rules(UriPolicy::default_rules())?;
+            config.review_policy = ReviewPolicy::default();
+            config.dispute_quorum = Dispute::MAX_QUORUM;
         }
 
         Ok(())
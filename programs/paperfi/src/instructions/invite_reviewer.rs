@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, Journal, Submission, BoardMember, ReviewInvitation, ReviewBounty };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+use crate::constants::MAX_INVITATION_SECONDS;
+
+#[derive(Accounts)]
+#[instruction(_id: u64, reviewer_commitment: [u8; 32])]
+pub struct InviteReviewer<'info> {
+    #[account(mut)]
+    pub inviter: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Journal editors invite for papers submitted to their journal, the paper owner passes none of these
+    pub journal: Option<Box<Account<'info, Journal>>>,
+
+    pub submission: Option<Box<Account<'info, Submission>>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    //Reserves a payout for the invited reviewer when the paper has an open bounty
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump = bounty.bump)]
+    pub bounty: Option<Box<Account<'info, ReviewBounty>>>,
+
+    #[account(
+        init,
+        payer = inviter,
+        space = ReviewInvitation::INIT_SPACE,
+        seeds = [b"invitation", paper.key().as_ref(), reviewer_commitment.as_ref()],
+        bump
+    )]
+    pub invitation: Box<Account<'info, ReviewInvitation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InviteReviewer<'info> {
+    pub fn invite_reviewer(
+        &mut self,
+        _id: u64,
+        reviewer_commitment: [u8; 32],
+        review_key: Pubkey,
+        deadline: u64,
+        bump: u8
+    ) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        let journal = self.check_inviter()?;
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            deadline > now && deadline - now <= MAX_INVITATION_SECONDS,
+            ErrorCode::InvalidDeadline
+        );
+
+        let bounty_reserved = match self.bounty.as_mut() {
+            Some(bounty) => {
+                bounty.reserve(now)?;
+                true
+            }
+            None => false,
+        };
+
+        self.invitation.set_inner(ReviewInvitation {
+            paper: self.paper.key(),
+            inviter: self.inviter.key(),
+            journal,
+            reviewer_commitment,
+            review_key,
+            reviewer: None,
+            review: None,
+            deadline,
+            status: InvitationStatus::Pending,
+            bounty_reserved,
+            verdict: None,
+            uri: String::new(),
+            scores: None,
+            version: 0,
+            submitted_at: 0,
+            timestamp: now,
+            bump,
+        });
+
+        Ok(())
+    }
+
+    //Returns the journal the editor invites for
+    fn check_inviter(&self) -> Result<Option<Pubkey>> {
+        if self.inviter.key() == self.paper.owner {
+            return Ok(None);
+        }
+
+        let journal = self.journal.as_ref().ok_or(ErrorCode::Unauthorized)?;
+        let submission = self.submission.as_ref().ok_or(ErrorCode::Unauthorized)?;
+        let journal_key = journal.key();
+
+        //the paper must have been submitted to the journal
+        require!(
+            submission.journal == journal_key && submission.paper == self.paper.key(),
+            ErrorCode::Unauthorized
+        );
+
+        let is_editor =
+            self.inviter.key() == journal.authority ||
+            self.board_member
+                .as_ref()
+                .is_some_and(|member| member.board == journal.board && member.member == self.inviter.key());
+        require!(is_editor, ErrorCode::NotJournalEditor);
+
+        Ok(Some(journal_key))
+    }
+}
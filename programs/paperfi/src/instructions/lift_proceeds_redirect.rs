@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct LiftProceedsRedirect<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", signer.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut, seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LiftProceedsRedirect<'info> {
+    //The claimant waives the redirected sales, or an arbitrator lifts them once the parties settled.
+    //The owner gets the sales back and can edit price and listing again
+    pub fn lift_proceeds_redirect(&mut self, _id: u64) -> Result<()> {
+        let recipient = self.paper.proceeds_recipient.ok_or(ErrorCode::ProceedsNotRedirected)?;
+        let signer = self.signer.key();
+        if signer != recipient {
+            authorize(&self.config, &signer, self.role_grant.as_deref(), Role::Arbitrator)?;
+        }
+
+        self.paper.proceeds_recipient = None;
+
+        Ok(())
+    }
+}
@@ -12,8 +12,7 @@ use mpl_core::{
         MasterEdition,
     },
 };
-use crate::state::{ PaperFiConfig };
-use crate::errors::ErrorCode;
+use crate::state::{ PaperFiConfig, RoleGrant };
 use crate::helpers::*;
 
 #[derive(Accounts)]
@@ -28,6 +27,9 @@ pub struct MakeBadge<'info> {
     #[account(seeds = [b"paperfi_config"], bump = config.bump)]
     pub config: Account<'info, PaperFiConfig>, //update authority
 
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
     #[account(address = MPL_CORE_ID)]
     /// CHECK: this account is checked by the address constraint
     pub mpl_core_program: UncheckedAccount<'info>,
@@ -37,8 +39,9 @@ pub struct MakeBadge<'info> {
 
 impl<'info> MakeBadge<'info> {
     pub fn make_badge(&mut self, args: CreateBadgeArgs) -> Result<()> {
-        // Ensure the signer is an approved admin
-        require!(self.config.admins.contains(&self.admin.key()), ErrorCode::Unauthorized);
+        // Ensure the signer is allowed to issue badges
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::BadgeIssuer)?;
+        validate_uri(&args.uri, &self.config.uri_policy)?;
 
         //Lets create a vector to hold our plugins to freeze the assets
         let mut collection_plugins: Vec<PluginAuthorityPair> = vec![];
@@ -1,4 +1,6 @@
 pub mod initialize;
+pub mod add_admin;
+pub mod remove_admin;
 pub mod new_user;
 pub mod edit_user;
 pub mod new_paper;
@@ -12,8 +14,65 @@ pub mod make_badge;
 pub mod print_badge;
 pub mod add_author;
 pub mod verify_author;
+pub mod paper_sales;
+pub mod set_uri_policy;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_fee;
+pub mod report_paper;
+pub mod dismiss_report;
+pub mod take_down_paper;
+pub mod appeal_takedown;
+pub mod resolve_takedown_appeal;
+pub mod set_review_policy;
+pub mod resolve_review_flag;
+pub mod open_review_round;
+pub mod commit_review;
+pub mod reveal_review;
+pub mod expire_commitment;
+pub mod respond_to_review;
+pub mod acknowledge_response;
+pub mod withdraw_review;
+pub mod set_conflicts;
+pub mod create_board;
+pub mod add_board_member;
+pub mod remove_board_member;
+pub mod set_paper_requirements;
+pub mod set_platform_requirements;
+pub mod fund_bounty;
+pub mod refund_bounty;
+pub mod set_reviewer_fee_share;
+pub mod claim_review_rewards;
+pub mod stake;
+pub mod request_unstake;
+pub mod unstake;
+pub mod report_review;
+pub mod slash_stake;
+pub mod set_min_stake;
+pub mod rate_review;
+pub mod create_journal;
+pub mod submit_paper;
+pub mod decide_submission;
+pub mod withdraw_submission;
+pub mod invite_reviewer;
+pub mod review_invited;
+pub mod reassign_invitation;
+pub mod expire_review_assignment;
+pub mod create_reviewer_pool;
+pub mod update_reviewer_pool;
+pub mod draw_reviewers;
+pub mod appeal_delisting;
+pub mod vote_on_appeal;
+pub mod open_dispute;
+pub mod submit_dispute_statement;
+pub mod rule_dispute;
+pub mod reveal_invited_review;
+pub mod set_dispute_quorum;
+pub mod lift_proceeds_redirect;
 
 pub use initialize::*;
+pub use add_admin::*;
+pub use remove_admin::*;
 pub use new_user::*;
 pub use edit_user::*;
 pub use new_paper::*;
@@ -27,3 +86,58 @@ pub use make_badge::*;
 pub use print_badge::*;
 pub use add_author::*;
 pub use verify_author::*;
+pub use paper_sales::*;
+pub use set_uri_policy::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_fee::*;
+pub use report_paper::*;
+pub use dismiss_report::*;
+pub use take_down_paper::*;
+pub use appeal_takedown::*;
+pub use resolve_takedown_appeal::*;
+pub use set_review_policy::*;
+pub use resolve_review_flag::*;
+pub use open_review_round::*;
+pub use commit_review::*;
+pub use reveal_review::*;
+pub use expire_commitment::*;
+pub use respond_to_review::*;
+pub use acknowledge_response::*;
+pub use withdraw_review::*;
+pub use set_conflicts::*;
+pub use create_board::*;
+pub use add_board_member::*;
+pub use remove_board_member::*;
+pub use set_paper_requirements::*;
+pub use set_platform_requirements::*;
+pub use fund_bounty::*;
+pub use refund_bounty::*;
+pub use set_reviewer_fee_share::*;
+pub use claim_review_rewards::*;
+pub use stake::*;
+pub use request_unstake::*;
+pub use unstake::*;
+pub use report_review::*;
+pub use slash_stake::*;
+pub use set_min_stake::*;
+pub use rate_review::*;
+pub use create_journal::*;
+pub use submit_paper::*;
+pub use decide_submission::*;
+pub use withdraw_submission::*;
+pub use invite_reviewer::*;
+pub use review_invited::*;
+pub use reassign_invitation::*;
+pub use expire_review_assignment::*;
+pub use create_reviewer_pool::*;
+pub use update_reviewer_pool::*;
+pub use draw_reviewers::*;
+pub use appeal_delisting::*;
+pub use vote_on_appeal::*;
+pub use open_dispute::*;
+pub use submit_dispute_statement::*;
+pub use rule_dispute::*;
+pub use reveal_invited_review::*;
+pub use set_dispute_quorum::*;
+pub use lift_proceeds_redirect::*;
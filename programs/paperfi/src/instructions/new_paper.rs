@@ -1,8 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::state::{ Paper, UserAccount, ReviewStatus, PaperAuthor };
+use crate::state::{
+    Paper,
+    UserAccount,
+    ReviewStatus,
+    PaperAuthor,
+    SalesStats,
+    PaperFiConfig,
+    Moderation,
+    ScoreSummary,
+    RewardPool,
+};
 use crate::errors::ErrorCode;
-use crate::{ validate_no_emojis };
-use crate::contains_emoji;
+use crate::helpers::*;
 
 #[derive(Accounts)]
 #[instruction(_id: u64)]
@@ -31,6 +40,9 @@ pub struct NewPaper<'info> {
     )]
     pub paper_author: Account<'info, PaperAuthor>,
 
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -43,19 +55,16 @@ impl<'info> NewPaper<'info> {
         uri: String,
         bump: &NewPaperBumps
     ) -> Result<()> {
-        //Is this safeguard needed?
-        validate_no_emojis!(&paper_info_url);
-        validate_no_emojis!(&uri);
+        validate_text(&paper_info_url, &PAPER_INFO_URL)?;
+        validate_text(&uri, &PAPER_URI)?;
+        validate_uri(&paper_info_url, &self.config.uri_policy)?;
+        validate_uri(&uri, &self.config.uri_policy)?;
 
         //Ensure price is either free (0) or in minimum lamports (0.001 Sol)
         require!(price == 0 || price >= 1_000_000, ErrorCode::IncorrectPricing);
 
         //default Review status
-        let review_status = ReviewStatus {
-            approved: 0,
-            rejected: 0,
-            review_requested: 0,
-        };
+        let review_status = ReviewStatus::default();
 
         //set paper
         self.paper.set_inner(Paper {
@@ -71,6 +80,19 @@ impl<'info> NewPaper<'info> {
             sales: 0,
             timestamp: Clock::get().unwrap().unix_timestamp as u64,
             paper_uri: uri,
+            sales_stats: SalesStats::default(),
+            moderation: Moderation::default(),
+            delisted_by_reviews: false,
+            flagged_by_reviews: false,
+            score_summary: ScoreSummary::default(),
+            review_round: None,
+            version_reviews: 0,
+            reviewer_requirements: None,
+            reward_pool: RewardPool::default(),
+            journal: None,
+            delisting_overturned: false,
+            overturned_rejections: 0,
+            proceeds_recipient: None,
         });
 
         //update user_account
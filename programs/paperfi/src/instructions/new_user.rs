@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::state::UserAccount;
-use crate::errors::ErrorCode;
+use crate::helpers::*;
 
 #[derive(Accounts)]
 pub struct NewUser<'info> {
@@ -25,9 +25,10 @@ pub struct NewUser<'info> {
 
 impl<'info> NewUser<'info> {
     pub fn new_user(&mut self, name: String, title: String, bumps: NewUserBumps) -> Result<()> {
-        require!(name.len() < 49 || title.len() < 33, ErrorCode::InvalidFieldLength);
-        require!(!name.is_empty() && !title.is_empty(), ErrorCode::FieldIsEmpty);
+        validate_text(&name, &USER_NAME)?;
+        validate_text(&title, &USER_TITLE)?;
 
+        let time = Clock::get()?.unix_timestamp as u64;
         self.user.set_inner(UserAccount {
             name,
             title,
@@ -37,7 +38,20 @@ impl<'info> NewUser<'info> {
             owner: self.signer.key(),
             bump: bumps.user,
             vault_bump: bumps.user_vault,
-            timestamp: Clock::get()?.unix_timestamp as u64,
+            timestamp: time,
+            badges: 0,
+            helpful_votes: 0,
+            unhelpful_votes: 0,
+            review_badge_record: 0,
+            paper_badge_record: 0,
+            purchase_badge_record: 0,
+            helpful_badge_record: 0,
+            affiliation: String::new(),
+            previous_affiliation: String::new(),
+            affiliation_updated_at: 0,
+            created_at: time,
+            missed_reviews: 0,
+            version_reviews: 0,
         });
         Ok(())
     }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Dispute, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64, respondent_id: u64)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [b"paper", claimant.key().as_ref(), &_id.to_le_bytes()],
+        bump = claimant_paper.bump
+    )]
+    pub claimant_paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        seeds = [b"paper", respondent_paper.owner.as_ref(), &respondent_id.to_le_bytes()],
+        bump = respondent_paper.bump
+    )]
+    pub respondent_paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = Dispute::INIT_SPACE,
+        seeds = [b"dispute", claimant_paper.key().as_ref(), respondent_paper.key().as_ref()],
+        bump
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenDispute<'info> {
+    pub fn open_dispute(
+        &mut self,
+        _id: u64,
+        _respondent_id: u64,
+        category: DisputeCategory,
+        evidence_uri: String,
+        bump: u8
+    ) -> Result<()> {
+        require!(self.respondent_paper.owner != self.claimant.key(), ErrorCode::InvalidDispute);
+
+        validate_text(&evidence_uri, &EVIDENCE_URI)?;
+        validate_uri(&evidence_uri, &self.config.uri_policy)?;
+
+        self.dispute.set_inner(Dispute {
+            claimant: self.claimant.key(),
+            claimant_paper: self.claimant_paper.key(),
+            respondent: self.respondent_paper.owner,
+            respondent_paper: self.respondent_paper.key(),
+            category,
+            evidence_uri,
+            claimant_statement: None,
+            respondent_statement: None,
+            status: DisputeStatus::Open,
+            arbitrator: None,
+            ruling_uri: None,
+            votes: Vec::new(),
+            quorum: self.config.dispute_quorum,
+            taken_down: false,
+            proceeds_redirected: false,
+            opened_at: Clock::get()?.unix_timestamp as u64,
+            ruled_at: 0,
+            bump,
+        });
+
+        Ok(())
+    }
+}
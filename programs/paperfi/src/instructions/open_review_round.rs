@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, ReviewRound };
+use crate::constants::MAX_ROUND_PHASE_SECONDS;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct OpenReviewRound<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", owner.key().as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+)]
+    pub paper: Account<'info, Paper>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenReviewRound<'info> {
+    pub fn open_review_round(
+        &mut self,
+        _id: u64,
+        commit_duration: u64,
+        reveal_duration: u64
+    ) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        require!(
+            (1..=MAX_ROUND_PHASE_SECONDS).contains(&commit_duration) &&
+                (1..=MAX_ROUND_PHASE_SECONDS).contains(&reveal_duration),
+            ErrorCode::InvalidRoundDuration
+        );
+
+        let time = Clock::get()?.unix_timestamp as u64;
+        require!(!self.paper.in_review_round(time), ErrorCode::ReviewRoundActive);
+
+        //commitments left from a previous round can still be expired, they keep their own deadline
+        let commit_deadline = time + commit_duration;
+        self.paper.review_round = Some(ReviewRound {
+            commit_deadline,
+            reveal_deadline: commit_deadline + reveal_duration,
+            commitments: 0,
+            revealed: 0,
+            expired: 0,
+        });
+        self.paper.timestamp = time;
+
+        Ok(())
+    }
+}
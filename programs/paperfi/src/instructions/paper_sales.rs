@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, SalesStats };
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct PaperSales<'info> {
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Account<'info, Paper>,
+}
+
+impl<'info> PaperSales<'info> {
+    //Read only, returns the paper sales analytics with the rolling buckets moved up to the current period
+    pub fn paper_sales(&self, _id: u64) -> Result<SalesStats> {
+        let mut stats = self.paper.sales_stats.clone();
+        stats.roll_periods(Clock::get()?.unix_timestamp as u64);
+        Ok(stats)
+    }
+}
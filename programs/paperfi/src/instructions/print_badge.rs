@@ -23,7 +23,7 @@ pub struct PrintBadge<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(seeds = [b"user", user.key().as_ref()], bump = user_account.bump)]
+    #[account(mut, seeds = [b"user", user.key().as_ref()], bump = user_account.bump)]
     pub user_account: Account<'info, UserAccount>,
 
     #[account(seeds = [b"paperfi_config"], bump = config.bump)]
@@ -48,6 +48,10 @@ pub struct PrintBadge<'info> {
 impl<'info> PrintBadge<'info> {
     pub fn print_badge(&mut self, args: PrintBadgeArgs) -> Result<()> {
         check_user_achievement!(self.user_account, args.name, args.record);
+        validate_uri(&args.uri, &self.config.uri_policy)?;
+        //badges held count in the reviewer reputation, once per tier.
+        //reviews behind a review badge can't be withdrawn anymore
+        self.user_account.claim_badge(&args.name, args.record)?;
 
         let mut edition_plugin: Vec<PluginAuthorityPair> = vec![];
 
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ UserAccount, Paper, PaperOwned, Review, ReviewRating };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct RateReview<'info> {
+    #[account(mut)]
+    pub rater: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Proof of purchase, the paper owner doesn't need one
+    #[account(
+        seeds = [b"purchase", rater.key().as_ref(), paper.key().as_ref()],
+        bump = paper_owned.bump
+    )]
+    pub paper_owned: Option<Box<Account<'info, PaperOwned>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"review",
+            review.owner.as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Box<Account<'info, Review>>,
+
+    #[account(mut, seeds = [b"user", review.owner.as_ref()], bump = reviewer_user_account.bump)]
+    pub reviewer_user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(
+        init,
+        payer = rater,
+        space = ReviewRating::INIT_SPACE,
+        seeds = [b"review_rating", rater.key().as_ref(), review.key().as_ref()],
+        bump
+    )]
+    pub rating: Box<Account<'info, ReviewRating>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RateReview<'info> {
+    pub fn rate_review(&mut self, _id: u64, helpful: bool, bump: u8) -> Result<()> {
+        //Only readers of the paper can judge its reviews. Each of them rates a review once,
+        //the rating PDA is per rater and review, and peer votes are capped in the reputation weight
+        require!(
+            self.paper.owner == self.rater.key() || self.paper_owned.is_some(),
+            ErrorCode::NotPaperReader
+        );
+        require!(self.review.owner != self.rater.key(), ErrorCode::Unauthorized);
+
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        self.rating.set_inner(ReviewRating {
+            review: self.review.key(),
+            rater: self.rater.key(),
+            helpful,
+            timestamp: now,
+            bump,
+        });
+
+        //Votes on the reviewer account feed the reputation weight and the helpful badge
+        let review = &mut self.review;
+        let reviewer = &mut self.reviewer_user_account;
+        if helpful {
+            review.helpful_votes = review.helpful_votes.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+            reviewer.helpful_votes = reviewer.helpful_votes.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        } else {
+            review.unhelpful_votes = review.unhelpful_votes.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+            reviewer.unhelpful_votes = reviewer.unhelpful_votes.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        Ok(())
+    }
+}
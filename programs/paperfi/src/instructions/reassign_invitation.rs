@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, ReviewInvitation, ReviewBounty };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+use crate::constants::MAX_INVITATION_SECONDS;
+
+#[derive(Accounts)]
+#[instruction(_id: u64, reviewer_commitment: [u8; 32])]
+pub struct ReassignInvitation<'info> {
+    #[account(mut)]
+    pub inviter: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        close = inviter,
+        has_one = inviter @ ErrorCode::Unauthorized,
+        has_one = paper @ ErrorCode::Unauthorized,
+        seeds = [b"invitation", paper.key().as_ref(), expired_invitation.reviewer_commitment.as_ref()],
+        bump = expired_invitation.bump
+    )]
+    pub expired_invitation: Box<Account<'info, ReviewInvitation>>,
+
+    #[account(
+        init,
+        payer = inviter,
+        space = ReviewInvitation::INIT_SPACE,
+        seeds = [b"invitation", paper.key().as_ref(), reviewer_commitment.as_ref()],
+        bump
+    )]
+    pub invitation: Box<Account<'info, ReviewInvitation>>,
+
+    //Reserves the payout again when the crank already gave the old reservation back
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump = bounty.bump)]
+    pub bounty: Option<Box<Account<'info, ReviewBounty>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReassignInvitation<'info> {
+    //Hands an unanswered invitation over to another reviewer
+    pub fn reassign_invitation(
+        &mut self,
+        _id: u64,
+        reviewer_commitment: [u8; 32],
+        review_key: Pubkey,
+        deadline: u64,
+        bump: u8
+    ) -> Result<()> {
+        //papers taken down can't be reviewed
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        let expired = &self.expired_invitation;
+        //a sealed review waits for its reveal, it can't be handed over
+        require!(
+            expired.status == InvitationStatus::Pending || expired.status == InvitationStatus::Expired,
+            ErrorCode::InvitationNotPending
+        );
+        require!(now > expired.deadline, ErrorCode::InvitationNotExpired);
+        require!(
+            deadline > now && deadline - now <= MAX_INVITATION_SECONDS,
+            ErrorCode::InvalidDeadline
+        );
+
+        //an unexpired reservation moves over, a released one is taken again from the bounty
+        let bounty_reserved = match self.bounty.as_mut() {
+            Some(bounty) if !expired.bounty_reserved => {
+                bounty.reserve(now)?;
+                true
+            }
+            _ => expired.bounty_reserved,
+        };
+
+        self.invitation.set_inner(ReviewInvitation {
+            paper: self.paper.key(),
+            inviter: self.inviter.key(),
+            journal: expired.journal,
+            reviewer_commitment,
+            review_key,
+            reviewer: None,
+            review: None,
+            deadline,
+            status: InvitationStatus::Pending,
+            bounty_reserved,
+            verdict: None,
+            uri: String::new(),
+            scores: None,
+            version: 0,
+            submitted_at: 0,
+            timestamp: now,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use anchor_spl::token::{ self, Token, TokenAccount, CloseAccount };
+
+use crate::state::{ Paper, ReviewBounty };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct RefundBounty<'info> {
+    #[account(mut, address = bounty.funder @ ErrorCode::Unauthorized)]
+    pub funder: Signer<'info>,
+
+    #[account(
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"bounty", paper.key().as_ref()],
+        bump = bounty.bump
+    )]
+    pub bounty: Box<Account<'info, ReviewBounty>>,
+
+    #[account(mut, seeds = [b"bounty_vault", bounty.key().as_ref()], bump = bounty.vault_bump)]
+    pub bounty_vault: SystemAccount<'info>,
+
+    //SPL bounties only
+    #[account(mut, seeds = [b"bounty_token_vault", bounty.key().as_ref()], bump)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub funder_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundBounty<'info> {
+    //Returns what was not paid out and closes the bounty so the authors can fund a new one
+    pub fn refund_bounty(&mut self, _id: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        //invitations holding a reservation give it back when they are reviewed or expired
+        require!(self.bounty.reserved_reviews == 0, ErrorCode::BountyReserved);
+        require!(self.bounty.is_settled(now), ErrorCode::BountyNotExpired);
+
+        let paper_key = self.paper.key();
+        let bounty_key = self.bounty.key();
+
+        //SOL vault, also holds nothing for SPL bounties
+        let vault_balance = self.bounty_vault.lamports();
+        if vault_balance > 0 {
+            let seeds = &[b"bounty_vault", bounty_key.as_ref(), &[self.bounty.vault_bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: self.bounty_vault.to_account_info(),
+                to: self.funder.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds
+            );
+            transfer(cpi_ctx, vault_balance)?;
+        }
+
+        if let Some(mint) = self.bounty.mint {
+            let (Some(vault), Some(to), Some(token_program)) = (
+                &self.bounty_token_vault,
+                &self.funder_token_account,
+                &self.token_program,
+            ) else {
+                return Err(ErrorCode::MissingTokenAccounts.into());
+            };
+            require!(to.mint == mint, ErrorCode::BountyMismatch);
+
+            let seeds = &[b"bounty", paper_key.as_ref(), &[self.bounty.bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            if vault.amount > 0 {
+                let cpi_accounts = token::Transfer {
+                    from: vault.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.bounty.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds
+                );
+                token::transfer(cpi_ctx, vault.amount)?;
+            }
+
+            //the token vault rent goes back to the funder too
+            let cpi_accounts = CloseAccount {
+                account: vault.to_account_info(),
+                destination: self.funder.to_account_info(),
+                authority: self.bounty.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds
+            );
+            token::close_account(cpi_ctx)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::PaperFiConfig;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RemoveAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+}
+
+impl<'info> RemoveAdmin<'info> {
+    pub fn remove_admin(&mut self, old_admin: Pubkey) -> Result<()> {
+        let config = &mut self.config;
+
+        require!(config.admins.contains(&self.admin.key()), ErrorCode::Unauthorized);
+        require!(config.admins.contains(&old_admin), ErrorCode::AdminNotFound);
+
+        //The config can never be left without an admin
+        require!(config.admins.len() > 1, ErrorCode::LastAdmin);
+
+        config.admins.retain(|admin| admin != &old_admin);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ EditorialBoard, BoardMember };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RemoveBoardMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        seeds = [b"board", authority.key().as_ref(), board.name.as_bytes()],
+        bump = board.bump
+    )]
+    pub board: Account<'info, EditorialBoard>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"board_member", board.key().as_ref(), board_member.member.as_ref()],
+        bump = board_member.bump
+    )]
+    pub board_member: Account<'info, BoardMember>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveBoardMember<'info> {
+    pub fn remove_board_member(&mut self) -> Result<()> {
+        self.board.members = self.board.members.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Review, Report, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ReportReview<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        seeds = [
+            b"review",
+            review.owner.as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Box<Account<'info, Review>>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = Report::INIT_SPACE,
+        seeds = [b"report", reporter.key().as_ref(), review.key().as_ref()],
+        bump
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReportReview<'info> {
+    pub fn report_review(
+        &mut self,
+        _id: u64,
+        category: ReportCategory,
+        evidence_uri: String,
+        bump: u8
+    ) -> Result<()> {
+        //Reviewers can't report their own reviews
+        require!(self.review.owner != self.reporter.key(), ErrorCode::Unauthorized);
+
+        validate_text(&evidence_uri, &EVIDENCE_URI)?;
+        validate_uri(&evidence_uri, &self.config.uri_policy)?;
+
+        self.report.set_inner(Report {
+            reporter: self.reporter.key(),
+            paper: self.paper.key(),
+            review: Some(self.review.key()),
+            reviewer: Some(self.review.owner),
+            category,
+            evidence_uri,
+            status: ReportStatus::Open,
+            moderator: None,
+            resolved_at: 0,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ReviewerStake;
+use crate::constants::STAKE_COOLDOWN_SECONDS;
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"stake", owner.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, ReviewerStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    //The cooldown leaves moderators time to slash reviews reported just before
+    pub fn request_unstake(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        self.stake.unlock_at = now + STAKE_COOLDOWN_SECONDS;
+        self.stake.timestamp = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ResolveReviewFlag<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", moderator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveReviewFlag<'info> {
+    //Moderator decision on a paper that crossed the rejection threshold while auto delisting is off
+    pub fn resolve_review_flag(&mut self, _id: u64, delist: bool) -> Result<()> {
+        authorize(&self.config, &self.moderator.key(), self.role_grant.as_deref(), Role::Moderator)?;
+        require!(self.paper.flagged_by_reviews, ErrorCode::PaperNotFlagged);
+
+        let paper = &mut self.paper;
+        paper.flagged_by_reviews = false;
+
+        //Delisted papers come back through the listing policy like automatic delistings
+        if delist && paper.listed {
+            paper.listed = false;
+            paper.delisted_by_reviews = true;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Report, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ResolveTakedownAppeal<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", moderator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        seeds = [b"report", report.reporter.as_ref(), paper.key().as_ref()],
+        bump = report.bump
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveTakedownAppeal<'info> {
+    pub fn resolve_takedown_appeal(&mut self, _id: u64, overturn: bool) -> Result<()> {
+        authorize(&self.config, &self.moderator.key(), self.role_grant.as_deref(), Role::Moderator)?;
+        require!(
+            self.paper.moderation.status == ModerationStatus::Appealed,
+            ErrorCode::NoPendingAppeal
+        );
+        require!(
+            self.paper.moderation.report == Some(self.report.key()),
+            ErrorCode::ReportMismatch
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        let paper = &mut self.paper;
+        paper.moderation.moderator = Some(self.moderator.key());
+        paper.moderation.decided_at = now;
+        paper.moderation.appeal_deadline = 0; //one appeal per takedown
+
+        if overturn {
+            paper.moderation.status = ModerationStatus::Active;
+            paper.listed = paper.moderation.listed_before;
+
+            let report = &mut self.report;
+            report.status = ReportStatus::Overturned;
+            report.moderator = Some(self.moderator.key());
+            report.resolved_at = now;
+        } else {
+            paper.moderation.status = ModerationStatus::TakenDown;
+        }
+
+        Ok(())
+    }
+}
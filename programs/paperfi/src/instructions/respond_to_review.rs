@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, PaperAuthor, Review, ReviewResponse, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct RespondToReview<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Account<'info, Paper>,
+
+    //Only needed when the signer is a co-author and not the owner
+    #[account(
+        seeds = [b"author", signer.key().as_ref(), paper.key().as_ref()],
+        bump = paper_author.bump
+    )]
+    pub paper_author: Option<Account<'info, PaperAuthor>>,
+
+    #[account(
+        seeds = [
+            b"review",
+            review.owner.as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ReviewResponse::INIT_SPACE,
+        seeds = [b"response", review.key().as_ref()],
+        bump
+    )]
+    pub response: Account<'info, ReviewResponse>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RespondToReview<'info> {
+    pub fn respond_to_review(
+        &mut self,
+        _id: u64,
+        uri: String,
+        linked_version: Option<u32>,
+        bump: u8
+    ) -> Result<()> {
+        //owner or an author that confirmed the authorship
+        let is_owner = self.paper.owner == self.signer.key();
+        let is_author = self.paper_author
+            .as_ref()
+            .is_some_and(|author| author.verify && author.author == self.signer.key());
+        require!(is_owner || is_author, ErrorCode::Unauthorized);
+
+        validate_text(&uri, &REVIEW_URI)?;
+        validate_uri(&uri, &self.config.uri_policy)?;
+        if let Some(version) = linked_version {
+            require!(version <= self.paper.version, ErrorCode::InvalidLinkedVersion);
+        }
+
+        self.response.set_inner(ReviewResponse {
+            review: self.review.key(),
+            paper: self.paper.key(),
+            responder: self.signer.key(),
+            response_uri: uri,
+            linked_version,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            acknowledged: false,
+            acknowledged_at: 0,
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ Token, TokenAccount };
+use mpl_core::accounts::BaseAssetV1;
+
+use crate::state::{
+    PaperReviewer,
+    UserAccount,
+    Paper,
+    Review,
+    PaperFiConfig,
+    ConflictList,
+    ReviewInvitation,
+    Submission,
+    ReviewBounty,
+    BountyPayout,
+    BoardMember,
+    ReviewerStake,
+};
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct RevealInvitedReview<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", signer.key().as_ref()],
+        bump = reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(mut, seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        has_one = paper @ ErrorCode::Unauthorized,
+        seeds = [b"invitation", paper.key().as_ref(), invitation.reviewer_commitment.as_ref()],
+        bump = invitation.bump
+    )]
+    pub invitation: Box<Account<'info, ReviewInvitation>>,
+
+    //Publishes the decision of the journal that invited, none for invitations of the owner
+    #[account(
+        seeds = [b"submission", invitation.journal.unwrap_or_default().as_ref(), paper.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Option<Box<Account<'info, Submission>>>,
+
+    #[account(seeds = [b"author", signer.key().as_ref(), paper.key().as_ref()], bump)]
+    /// CHECKED : Must be empty, authors can't review their own papers
+    pub paper_author: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"conflicts", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ConflictList::check when the authors created it
+    pub conflicts: UncheckedAccount<'info>,
+
+    //An invitation does not waive the reviewer requirements, same proofs as ReviewPaper
+    pub badge_asset: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    #[account(seeds = [b"stake", signer.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Box<Account<'info, ReviewerStake>>>,
+
+    //Pays the reserved bounty, empty once it was refunded. See ReviewPaper for the vault checks
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ReviewBounty::load when it exists
+    pub bounty: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub bounty_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub reviewer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = Review::INIT_SPACE,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            paper.version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Box<Account<'info, Review>>,
+
+    //Counts the paper once on the reviewer profile across its versions
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PaperReviewer::INIT_SPACE,
+        seeds = [b"paper_reviewer", signer.key().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub paper_reviewer: Box<Account<'info, PaperReviewer>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealInvitedReview<'info> {
+    //The salt proves the signer is the invited reviewer, the sealed review goes through the same checks as ReviewPaper
+    pub fn reveal_invited_review(&mut self, _id: u64, salt: [u8; 32], paper_reviewer_bump: u8) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp as u64;
+        let invitation = &self.invitation;
+        require!(invitation.status == InvitationStatus::Submitted, ErrorCode::InvitationNotPending);
+        require!(
+            time >= invitation.reveal_opens(self.submission.as_deref().map(|submission| &**submission)) &&
+                time <= invitation.reveal_deadline(),
+            ErrorCode::RevealPhaseNotOpen
+        );
+        require!(
+            ReviewInvitation::hash(&self.signer.key(), &salt) == invitation.reviewer_commitment,
+            ErrorCode::CommitmentMismatch
+        );
+        require!(invitation.version == self.paper.version, ErrorCode::ReviewVersionStale);
+
+        require!(self.paper.owner.key() != self.signer.key(), ErrorCode::Unauthorized);
+        require!(self.paper_author.to_account_info().data_is_empty(), ErrorCode::Unauthorized);
+        ConflictList::check(&self.conflicts, &self.reviewer_user_account)?;
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        require!(!self.paper.in_review_round(time), ErrorCode::ReviewRoundActive);
+
+        self.paper.check_reviewer(
+            &self.config.reviewer_requirements,
+            &self.reviewer_user_account,
+            time,
+            self.badge_asset.as_deref().map(|asset| &**asset),
+            self.board_member.as_deref().map(|member| &**member)
+        )?;
+        ReviewerStake::check(self.stake.as_deref().map(|stake| &**stake), self.config.min_reviewer_stake)?;
+
+        let verdict = invitation.verdict.ok_or(ErrorCode::InvitationNotPending)?;
+        let scores = invitation.scores;
+        let weight = self.reviewer_user_account.reputation_weight();
+
+        self.review.set_inner(Review {
+            reward_debt: self.paper.reward_pool.acc_per_review,
+            ..Review::new(
+                self.signer.key(),
+                self.paper.key(),
+                self.paper.version,
+                verdict,
+                invitation.uri.clone(),
+                weight,
+                scores,
+                time
+            )
+        });
+
+        let paper = &mut self.paper;
+        paper.add_review(&verdict, weight, scores.as_ref(), &self.config.review_policy, time)?;
+
+        let first_on_paper = self.paper_reviewer.add(self.signer.key(), self.paper.key(), paper_reviewer_bump);
+        self.reviewer_user_account.add_review(first_on_paper, time);
+
+        let invitation = &mut self.invitation;
+        invitation.status = InvitationStatus::Completed;
+        invitation.reviewer = Some(self.signer.key());
+        invitation.review = Some(self.review.key());
+
+        if invitation.bounty_reserved {
+            invitation.bounty_reserved = false;
+            let submitted_at = invitation.submitted_at;
+            self.pay_reserved_bounty(submitted_at)?;
+        }
+
+        Ok(())
+    }
+
+    //The reserved slot turns into a payout, or is given back when the bounty had expired by the submission
+    fn pay_reserved_bounty(&mut self, submitted_at: u64) -> Result<()> {
+        let Some(mut bounty) = ReviewBounty::load(&self.bounty)? else {
+            return Ok(());
+        };
+        bounty.release();
+        if bounty.is_settled(submitted_at) {
+            return bounty.store(&self.bounty);
+        }
+
+        ReviewBounty::pay(&bounty, &self.bounty, &BountyPayout {
+            reviewer: &self.signer,
+            bounty_vault: self.bounty_vault.as_ref(),
+            bounty_token_vault: self.bounty_token_vault.as_deref(),
+            reviewer_token_account: self.reviewer_token_account.as_deref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        })?;
+
+        bounty.paid_reviews += 1;
+        bounty.store(&self.bounty)?;
+        //rewarded reviews can't be withdrawn
+        self.review.rewarded = true;
+
+        Ok(())
+    }
+}
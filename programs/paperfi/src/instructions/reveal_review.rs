@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{ Token, TokenAccount };
+use mpl_core::accounts::BaseAssetV1;
+
+use crate::state::{
+    PaperReviewer,
+    UserAccount,
+    Paper,
+    Review,
+    ReviewCommitment,
+    ReviewScores,
+    PaperFiConfig,
+    ConflictList,
+    BoardMember,
+    ReviewBounty,
+    ReviewerStake,
+    BountyPayout,
+};
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct RevealReview<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"user", signer.key().as_ref()],
+      bump = reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Box<Account<'info, UserAccount>>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"commitment", signer.key().as_ref(), paper.key().as_ref()],
+        bump = commitment.bump
+    )]
+    pub commitment: Box<Account<'info, ReviewCommitment>>,
+
+    //Same proofs as ReviewPaper, the reviewer may have lost eligibility since the commitment
+    #[account(seeds = [b"author", signer.key().as_ref(), paper.key().as_ref()], bump)]
+    /// CHECKED : Must be empty, authors can't review their own papers
+    pub paper_author: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"conflicts", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ConflictList::check when the authors created it
+    pub conflicts: UncheckedAccount<'info>,
+
+    pub badge_asset: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    #[account(seeds = [b"stake", signer.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Box<Account<'info, ReviewerStake>>>,
+
+    //Bounty accounts, see ReviewPaper
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump = bounty.bump)]
+    pub bounty: Option<Box<Account<'info, ReviewBounty>>>,
+
+    #[account(mut)]
+    pub bounty_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub reviewer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = Review::INIT_SPACE,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            paper.version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Box<Account<'info, Review>>,
+
+    //Counts the paper once on the reviewer profile across its versions
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PaperReviewer::INIT_SPACE,
+        seeds = [b"paper_reviewer", signer.key().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub paper_reviewer: Box<Account<'info, PaperReviewer>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealReview<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_review(
+        &mut self,
+        _id: u64,
+        verdict: Verdict,
+        uri: String,
+        scores: Option<ReviewScores>,
+        salt: [u8; 32],
+        disclosure: Option<String>,
+        paper_reviewer_bump: u8
+    ) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        let time = Clock::get()?.unix_timestamp as u64;
+        let expected = ReviewCommitment::hash(
+            &self.signer.key(),
+            &self.paper.key(),
+            self.paper.version,
+            verdict,
+            scores.as_ref(),
+            &uri,
+            &salt
+        );
+        let round = self.paper.review_round.as_ref().ok_or(ErrorCode::NoReviewRound)?;
+        require!(
+            time >= round.commit_deadline && time < self.commitment.reveal_deadline,
+            ErrorCode::RevealPhaseNotOpen
+        );
+        require!(
+            expected == self.commitment.commitment,
+            ErrorCode::CommitmentMismatch
+        );
+
+        //Same reviewer rules as review_paper, checked again as the reviewer may have changed since the commitment
+        require!(self.paper.owner.key() != self.signer.key(), ErrorCode::Unauthorized);
+        require!(self.paper_author.to_account_info().data_is_empty(), ErrorCode::Unauthorized);
+        ConflictList::check(&self.conflicts, &self.reviewer_user_account)?;
+        self.paper.check_reviewer(
+            &self.config.reviewer_requirements,
+            &self.reviewer_user_account,
+            time,
+            self.badge_asset.as_deref().map(|asset| &**asset),
+            self.board_member.as_deref().map(|member| &**member)
+        )?;
+        ReviewerStake::check(self.stake.as_deref().map(|stake| &**stake), self.config.min_reviewer_stake)?;
+
+        validate_text(&uri, &REVIEW_URI)?;
+        validate_uri(&uri, &self.config.uri_policy)?;
+        if let Some(scores) = &scores {
+            scores.validate()?;
+        }
+        if let Some(disclosure) = &disclosure {
+            validate_text(disclosure, &CONFLICT_DISCLOSURE)?;
+        }
+
+        if let Some(round) = self.paper.review_round.as_mut() {
+            round.revealed = round.revealed.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        //The review status only moves now, once the verdict is public
+        let weight = self.reviewer_user_account.reputation_weight();
+        self.review.set_inner(Review {
+            declared_conflict: disclosure,
+            //only fees from later sales are shared with this review
+            reward_debt: self.paper.reward_pool.acc_per_review,
+            ..Review::new(
+                self.signer.key(),
+                self.paper.key(),
+                self.paper.version,
+                verdict,
+                uri,
+                weight,
+                scores,
+                time
+            )
+        });
+
+        let paper = &mut self.paper;
+        paper.add_review(&verdict, weight, scores.as_ref(), &self.config.review_policy, time)?;
+
+        let first_on_paper = self.paper_reviewer.add(self.signer.key(), self.paper.key(), paper_reviewer_bump);
+        self.reviewer_user_account.add_review(first_on_paper, time);
+
+        //revealed reviews are paid like direct ones
+        if self.bounty.as_ref().is_some_and(|bounty| bounty.is_open(time)) {
+            self.pay_bounty()?;
+        }
+
+        Ok(())
+    }
+
+    fn pay_bounty(&mut self) -> Result<()> {
+        let bounty = self.bounty.as_ref().ok_or(ErrorCode::MissingTokenAccounts)?;
+        ReviewBounty::pay(bounty, &bounty.to_account_info(), &BountyPayout {
+            reviewer: &self.signer,
+            bounty_vault: self.bounty_vault.as_ref(),
+            bounty_token_vault: self.bounty_token_vault.as_deref(),
+            reviewer_token_account: self.reviewer_token_account.as_deref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        })?;
+
+        if let Some(bounty) = self.bounty.as_mut() {
+            bounty.paid_reviews += 1;
+        }
+        //rewarded reviews can't be withdrawn
+        self.review.rewarded = true;
+
+        Ok(())
+    }
+}
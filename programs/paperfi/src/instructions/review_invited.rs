@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, PaperFiConfig, ReviewScores, ReviewInvitation };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct ReviewInvited<'info> {
+    //One-off key of the invitation, the reviewer wallet stays out of the transaction
+    pub review_key: Signer<'info>,
+
+    #[account(seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Invited reviewers don't need a purchase
+    #[account(
+        mut,
+        has_one = paper @ ErrorCode::Unauthorized,
+        has_one = review_key @ ErrorCode::Unauthorized,
+        seeds = [b"invitation", paper.key().as_ref(), invitation.reviewer_commitment.as_ref()],
+        bump = invitation.bump
+    )]
+    pub invitation: Box<Account<'info, ReviewInvitation>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+}
+
+impl<'info> ReviewInvited<'info> {
+    //The review is sealed in the invitation, RevealInvitedReview checks the reviewer and counts it
+    pub fn review_invited(
+        &mut self,
+        _id: u64,
+        verdict: Verdict,
+        uri: String,
+        scores: Option<ReviewScores>
+    ) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp as u64;
+        require!(self.invitation.is_pending(time), ErrorCode::InvitationNotPending);
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
+        validate_text(&uri, &REVIEW_URI)?;
+        validate_uri(&uri, &self.config.uri_policy)?;
+        if let Some(scores) = &scores {
+            scores.validate()?;
+        }
+
+        let invitation = &mut self.invitation;
+        invitation.status = InvitationStatus::Submitted;
+        invitation.verdict = Some(verdict);
+        invitation.uri = uri;
+        invitation.scores = scores;
+        invitation.version = self.paper.version;
+        invitation.submitted_at = time;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,21 @@
 use anchor_lang::prelude::*;
-
-use crate::state::{ UserAccount, Paper, Review, PaperOwned };
+use anchor_spl::token::{ Token, TokenAccount };
+use mpl_core::accounts::BaseAssetV1;
+
+use crate::state::{
+    PaperReviewer,
+    UserAccount,
+    Paper,
+    Review,
+    PaperOwned,
+    PaperFiConfig,
+    ReviewScores,
+    ConflictList,
+    BoardMember,
+    ReviewBounty,
+    ReviewerStake,
+    BountyPayout,
+};
 use crate::errors::ErrorCode;
 use crate::helpers::*;
 
@@ -41,54 +56,163 @@ pub struct ReviewPaper<'info> {
     )]
     pub paper_owned: Box<Account<'info, PaperOwned>>, // Boxed
 
+    #[account(seeds = [b"conflicts", paper.key().as_ref()], bump)]
+    /// CHECKED : Deserialized in ConflictList::check when the authors created it
+    pub conflicts: UncheckedAccount<'info>,
+
+    //Proofs for the reviewer requirements, only needed when the paper or platform requires them
+    pub badge_asset: Option<Box<Account<'info, BaseAssetV1>>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    //Only needed when the platform requires a stake
+    #[account(seeds = [b"stake", signer.key().as_ref()], bump = stake.bump)]
+    pub stake: Option<Box<Account<'info, ReviewerStake>>>,
+
+    //Bounty accounts, only needed to get paid when the paper has an open bounty
+    #[account(mut, seeds = [b"bounty", paper.key().as_ref()], bump = bounty.bump)]
+    pub bounty: Option<Box<Account<'info, ReviewBounty>>>,
+
+    //The vaults can't be seeded from an optional account, the SOL vault address is checked in ReviewBounty::pay
+    //and the token vault must be owned by the bounty for the signed transfer to go through
+    #[account(mut)]
+    pub bounty_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub bounty_token_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub reviewer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     #[account(
         init,
         payer = signer,
         space = Review::INIT_SPACE,
-        seeds = [b"review", signer.key().as_ref(), paper.key().as_ref()],
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            paper.version.to_le_bytes().as_ref(),
+        ],
         bump
     )]
     pub review: Account<'info, Review>, // Box to?
 
+    //Counts the paper once on the reviewer profile across its versions
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PaperReviewer::INIT_SPACE,
+        seeds = [b"paper_reviewer", signer.key().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub paper_reviewer: Box<Account<'info, PaperReviewer>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ReviewPaper<'info> {
     //When selecting the paper to review, the client has the PDA info
-    pub fn review_paper(&mut self, _id: u64, verdict: Verdict, uri: String) -> Result<()> {
+    pub fn review_paper(
+        &mut self,
+        _id: u64,
+        verdict: Verdict,
+        uri: String,
+        scores: Option<ReviewScores>,
+        disclosure: Option<String>,
+        paper_reviewer_bump: u8
+    ) -> Result<()> {
         //Paper owners can't review own papers
         require!(self.paper.owner.key() != self.signer.key(), ErrorCode::Unauthorized);
 
         //check that author account doesn't exist by checking that the PDA has no data, authors can't review their own papers
         require!(self.paper_author.to_account_info().data_is_empty(), ErrorCode::Unauthorized);
 
+        //wallets and institutions excluded by the authors
+        ConflictList::check(&self.conflicts, &self.reviewer_user_account)?;
+
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+
         let time = Clock::get()?.unix_timestamp as u64;
+        //during a round verdicts stay hidden until the reveal
+        require!(!self.paper.in_review_round(time), ErrorCode::ReviewRoundActive);
+
+        //eligibility set by the platform and by the owner
+        self.paper.check_reviewer(
+            &self.config.reviewer_requirements,
+            &self.reviewer_user_account,
+            time,
+            self.badge_asset.as_deref().map(|asset| &**asset),
+            self.board_member.as_deref().map(|member| &**member)
+        )?;
+        ReviewerStake::check(self.stake.as_deref().map(|stake| &**stake), self.config.min_reviewer_stake)?;
+
+        validate_text(&uri, &REVIEW_URI)?;
+        validate_uri(&uri, &self.config.uri_policy)?;
+        if let Some(scores) = &scores {
+            scores.validate()?;
+        }
+        if let Some(disclosure) = &disclosure {
+            validate_text(disclosure, &CONFLICT_DISCLOSURE)?;
+        }
+
+        //the weight is fixed when the review is written so edits move exactly what was added
+        let weight = self.reviewer_user_account.reputation_weight();
+
         //create review
         self.review.set_inner(Review {
-            owner: self.signer.key(),
-            paper: self.paper.key(),
-            verdict: verdict.clone(),
-            timestamp: time.clone(),
-            review_uri: uri,
+            declared_conflict: disclosure,
+            //only fees from later sales are shared with this review
+            reward_debt: self.paper.reward_pool.acc_per_review,
+            ..Review::new(
+                self.signer.key(),
+                self.paper.key(),
+                self.paper.version,
+                verdict,
+                uri,
+                weight,
+                scores,
+                time
+            )
         });
 
         //update paper state
         let paper = &mut self.paper;
-        paper.reviews += 1;
-        paper.timestamp = time.clone();
-        paper.review_status.update(&verdict);
+        paper.add_review(&verdict, weight, scores.as_ref(), &self.config.review_policy, time)?;
+        msg!("Rejection ratio (bps): {}", paper.review_status.rejection_bps(&self.config.review_policy));
 
-        let ratio = paper.review_status.rejection_ratio();
-        msg!("Rejection ratio before if statement: {}", ratio);
+        let first_on_paper = self.paper_reviewer.add(self.signer.key(), self.paper.key(), paper_reviewer_bump);
+        self.reviewer_user_account.add_review(first_on_paper, time);
 
-        if ratio > 20 {
-            paper.listed = false;
+        //the review is accepted, pay it if the paper has an open bounty
+        if self.bounty.as_ref().is_some_and(|bounty| bounty.is_open(time)) {
+            self.pay_bounty()?;
         }
 
-        let user = &mut self.reviewer_user_account;
-        //update user state
-        user.reviews += 1;
-        user.timestamp = time;
+        Ok(())
+    }
+
+    fn pay_bounty(&mut self) -> Result<()> {
+        let bounty = self.bounty.as_ref().ok_or(ErrorCode::MissingTokenAccounts)?;
+        ReviewBounty::pay(bounty, &bounty.to_account_info(), &BountyPayout {
+            reviewer: &self.signer,
+            bounty_vault: self.bounty_vault.as_ref(),
+            bounty_token_vault: self.bounty_token_vault.as_deref(),
+            reviewer_token_account: self.reviewer_token_account.as_deref(),
+            token_program: self.token_program.as_ref(),
+            system_program: &self.system_program,
+        })?;
+
+        if let Some(bounty) = self.bounty.as_mut() {
+            bounty.paid_reviews += 1;
+        }
+        //rewarded reviews can't be withdrawn
+        self.review.rewarded = true;
 
         Ok(())
     }
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = admin_role.bump)]
+    pub admin_role: Option<Account<'info, RoleGrant>>,
+
+    #[account(mut, seeds = [b"role", role_grant.wallet.as_ref()], bump = role_grant.bump)]
+    pub role_grant: Account<'info, RoleGrant>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevokeRole<'info> {
+    pub fn revoke_role(&mut self, role: Role) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.admin_role.as_deref(), Role::SuperAdmin)?;
+
+        //The account is kept with no roles so it can be granted again without paying rent twice
+        let grant = &mut self.role_grant;
+        grant.roles &= !role.mask();
+        grant.granted_by = self.admin.key();
+        grant.timestamp = Clock::get()?.unix_timestamp as u64;
+
+        Ok(())
+    }
+}
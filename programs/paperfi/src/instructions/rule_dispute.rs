@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Dispute, DisputeVote, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct RuleDispute<'info> {
+    #[account(mut)]
+    pub arbitrator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", arbitrator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.claimant_paper.as_ref(), dispute.respondent_paper.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(mut, address = dispute.respondent_paper @ ErrorCode::InvalidDispute)]
+    pub respondent_paper: Box<Account<'info, Paper>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RuleDispute<'info> {
+    //Each arbitrator votes a ruling, the quorum on the same side decides. An upheld dispute can take the
+    //respondent paper down and send its future sales to the claimant
+    pub fn rule_dispute(
+        &mut self,
+        upheld: bool,
+        take_down: bool,
+        redirect_proceeds: bool,
+        ruling_uri: String
+    ) -> Result<()> {
+        let arbitrator = self.arbitrator.key();
+        authorize(&self.config, &arbitrator, self.role_grant.as_deref(), Role::Arbitrator)?;
+
+        let dispute = &mut self.dispute;
+        require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeClosed);
+        require!(
+            arbitrator != dispute.claimant && arbitrator != dispute.respondent,
+            ErrorCode::Unauthorized
+        );
+        require!(!dispute.has_voted(&arbitrator), ErrorCode::AlreadyVoted);
+
+        validate_text(&ruling_uri, &EVIDENCE_URI)?;
+        validate_uri(&ruling_uri, &self.config.uri_policy)?;
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        dispute.votes.push(DisputeVote {
+            arbitrator,
+            upheld,
+            take_down: upheld && take_down,
+            redirect_proceeds: upheld && redirect_proceeds,
+            timestamp: now,
+        });
+
+        let Some(ruling) = dispute.outcome() else {
+            return Ok(());
+        };
+        dispute.arbitrator = Some(arbitrator);
+        dispute.ruling_uri = Some(ruling_uri);
+        dispute.ruled_at = now;
+
+        if !ruling.upheld {
+            dispute.status = DisputeStatus::Dismissed;
+            return Ok(());
+        }
+        dispute.status = DisputeStatus::Upheld;
+
+        let paper = &mut self.respondent_paper;
+        if ruling.redirect_proceeds {
+            paper.proceeds_recipient = Some(dispute.claimant);
+            dispute.proceeds_redirected = true;
+        }
+        if ruling.take_down {
+            //a panel of arbitrators already heard both parties, the takedown can't be appealed
+            //a paper already down stays down for its own reason
+            dispute.taken_down = paper.take_down(arbitrator, dispute.key(), now, 0);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, PaperAuthor, ConflictList };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct SetConflicts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+    )]
+    pub paper: Account<'info, Paper>,
+
+    //Only needed when the signer is a co-author and not the owner
+    #[account(
+        seeds = [b"author", signer.key().as_ref(), paper.key().as_ref()],
+        bump = paper_author.bump
+    )]
+    pub paper_author: Option<Account<'info, PaperAuthor>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ConflictList::INIT_SPACE,
+        seeds = [b"conflicts", paper.key().as_ref()],
+        bump
+    )]
+    pub conflicts: Account<'info, ConflictList>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetConflicts<'info> {
+    //Replaces the whole list
+    pub fn set_conflicts(
+        &mut self,
+        _id: u64,
+        wallets: Vec<Pubkey>,
+        affiliations: Vec<String>,
+        bump: u8
+    ) -> Result<()> {
+        let is_owner = self.paper.owner == self.signer.key();
+        let is_author = self.paper_author
+            .as_ref()
+            .is_some_and(|author| author.verify && author.author == self.signer.key());
+        require!(is_owner || is_author, ErrorCode::Unauthorized);
+
+        ConflictList::validate(&wallets, &affiliations)?;
+
+        self.conflicts.set_inner(ConflictList {
+            paper: self.paper.key(),
+            excluded_wallets: wallets,
+            excluded_affiliations: affiliations,
+            updated_by: self.signer.key(),
+            timestamp: Clock::get()?.unix_timestamp as u64,
+            bump,
+        });
+
+        Ok(())
+    }
+}
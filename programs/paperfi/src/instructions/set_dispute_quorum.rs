@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant, Dispute };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetDisputeQuorum<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetDisputeQuorum<'info> {
+    pub fn set_dispute_quorum(&mut self, quorum: u8) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::SuperAdmin)?;
+
+        //1 lets a single arbitrator rule, open disputes keep the quorum they were opened with
+        require!(quorum > 0 && quorum <= Dispute::MAX_QUORUM, ErrorCode::InvalidQuorum);
+        self.config.dispute_quorum = quorum;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::constants::MAX_FEE_PERCENTAGE;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFee<'info> {
+    pub fn set_fee(&mut self, fee: u8) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::FeeManager)?;
+        require!(fee <= MAX_FEE_PERCENTAGE, ErrorCode::InvalidFee);
+
+        self.config.fee = Some(fee);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetMinStake<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMinStake<'info> {
+    pub fn set_min_stake(&mut self, min_stake: u64) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::SuperAdmin)?;
+
+        //0 lets reviewers review without a stake
+        self.config.min_reviewer_stake = min_stake;
+
+        Ok(())
+    }
+}
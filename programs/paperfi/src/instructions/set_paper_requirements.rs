@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, ReviewerRequirements };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct SetPaperRequirements<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", owner.key().as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+)]
+    pub paper: Account<'info, Paper>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPaperRequirements<'info> {
+    //None removes the paper requirements, the platform ones still apply.
+    //Only set before the version gets reviews, otherwise the owner could lock out the reviewers still to come
+    pub fn set_paper_requirements(
+        &mut self,
+        _id: u64,
+        requirements: Option<ReviewerRequirements>
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            self.paper.version_reviews == 0 && !self.paper.in_review_round(now),
+            ErrorCode::RequirementsLocked
+        );
+
+        self.paper.reviewer_requirements = requirements;
+        self.paper.timestamp = now;
+        Ok(())
+    }
+}
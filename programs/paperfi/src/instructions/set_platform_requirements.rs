@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, ReviewerRequirements, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetPlatformRequirements<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPlatformRequirements<'info> {
+    pub fn set_platform_requirements(&mut self, requirements: ReviewerRequirements) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::SuperAdmin)?;
+
+        self.config.reviewer_requirements = requirements;
+
+        Ok(())
+    }
+}
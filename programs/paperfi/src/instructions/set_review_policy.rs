@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, ReviewPolicy, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetReviewPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReviewPolicy<'info> {
+    pub fn set_review_policy(&mut self, policy: ReviewPolicy) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::SuperAdmin)?;
+        policy.validate()?;
+
+        //Papers are re-evaluated with the new policy on their next review change
+        self.config.review_policy = policy;
+
+        Ok(())
+    }
+}
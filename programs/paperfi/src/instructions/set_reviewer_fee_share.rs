@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::constants::MAX_REVIEWER_FEE_SHARE_BPS;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetReviewerFeeShare<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReviewerFeeShare<'info> {
+    pub fn set_reviewer_fee_share(&mut self, share_bps: u16) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::FeeManager)?;
+        require!(share_bps <= MAX_REVIEWER_FEE_SHARE_BPS, ErrorCode::InvalidFeeShare);
+
+        self.config.reviewer_fee_share_bps = share_bps;
+
+        Ok(())
+    }
+}
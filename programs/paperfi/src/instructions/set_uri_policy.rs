@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::{ PaperFiConfig, UriRule, RoleGrant };
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SetUriPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    #[account(seeds = [b"role", admin.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetUriPolicy<'info> {
+    pub fn set_uri_policy(&mut self, rules: Vec<UriRule>) -> Result<()> {
+        authorize(&self.config, &self.admin.key(), self.role_grant.as_deref(), Role::SuperAdmin)?;
+
+        //Replaces the whole allowlist, an empty list only enforces well formed URIs
+        self.config.uri_policy.set_rules(rules)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Report, ReviewerStake, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SlashStake<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", moderator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"report", report.reporter.as_ref(), report.target().as_ref()],
+        bump = report.bump
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    #[account(address = report.paper @ ErrorCode::ReportMismatch)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    //Reviewer stored in the report, withdrawing the review does not escape the slash
+    #[account(
+        mut,
+        seeds = [b"stake", stake.owner.as_ref()],
+        bump = stake.bump,
+        constraint = report.reviewer == Some(stake.owner) @ ErrorCode::ReportMismatch
+    )]
+    pub stake: Box<Account<'info, ReviewerStake>>,
+
+    //Treasury
+    #[account(mut, seeds = [b"config_vault", config.key().as_ref()], bump = config.vault_bump)]
+    pub config_vault: SystemAccount<'info>,
+
+    //Harmed author
+    #[account(mut, seeds = [b"user_vault", paper.owner.as_ref()], bump)]
+    pub author_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SlashStake<'info> {
+    //Upholds a review report and moves part of the reviewer stake to the treasury or to the author
+    pub fn slash_stake(&mut self, amount: u64, to_author: bool) -> Result<()> {
+        authorize(&self.config, &self.moderator.key(), self.role_grant.as_deref(), Role::Moderator)?;
+        require!(self.report.review.is_some(), ErrorCode::NotAReviewReport);
+        require!(self.report.status == ReportStatus::Open, ErrorCode::ReportAlreadyResolved);
+        require!(amount > 0 && amount <= self.stake.amount, ErrorCode::InsufficientStake);
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        let report = &mut self.report;
+        report.status = ReportStatus::Upheld;
+        report.moderator = Some(self.moderator.key());
+        report.resolved_at = now;
+
+        let stake = &mut self.stake;
+        stake.amount -= amount;
+        stake.slashed = stake.slashed.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        stake.timestamp = now;
+
+        //The stake lamports sit on top of the account rent, the program owns the account so it can move them
+        let destination = if to_author {
+            self.author_vault.to_account_info()
+        } else {
+            self.config_vault.to_account_info()
+        };
+        **stake.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **destination.try_borrow_mut_lamports()? += amount;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+
+use crate::state::ReviewerStake;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ReviewerStake::INIT_SPACE,
+        seeds = [b"stake", owner.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, ReviewerStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Stake<'info> {
+    //Adding stake also cancels a pending unstake
+    pub fn stake(&mut self, amount: u64, bump: u8) -> Result<()> {
+        require!(amount > 0, ErrorCode::InsufficientFunds);
+
+        let cpi_accounts = Transfer {
+            from: self.owner.to_account_info(),
+            to: self.stake.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+        transfer(cpi_ctx, amount)?;
+
+        let stake = &mut self.stake;
+        stake.owner = self.owner.key();
+        stake.amount = stake.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        stake.unlock_at = 0;
+        stake.timestamp = Clock::get()?.unix_timestamp as u64;
+        stake.bump = bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Dispute, PaperFiConfig };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct SubmitDisputeStatement<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.claimant_paper.as_ref(), dispute.respondent_paper.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitDisputeStatement<'info> {
+    //Each party keeps one statement and can replace it until the ruling
+    pub fn submit_dispute_statement(&mut self, statement_uri: String) -> Result<()> {
+        let dispute = &mut self.dispute;
+        require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeClosed);
+
+        validate_text(&statement_uri, &EVIDENCE_URI)?;
+        validate_uri(&statement_uri, &self.config.uri_policy)?;
+
+        let signer = self.signer.key();
+        if signer == dispute.claimant {
+            dispute.claimant_statement = Some(statement_uri);
+        } else if signer == dispute.respondent {
+            dispute.respondent_statement = Some(statement_uri);
+        } else {
+            return Err(ErrorCode::Unauthorized.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Paper, Journal, Submission };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct SubmitPaper<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ ErrorCode::Unauthorized,
+        seeds = [b"paper", owner.key().as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(mut, seeds = [b"journal", journal.authority.as_ref(), journal.name.as_bytes()], bump = journal.bump)]
+    pub journal: Box<Account<'info, Journal>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Submission::INIT_SPACE,
+        seeds = [b"submission", journal.key().as_ref(), paper.key().as_ref()],
+        bump
+    )]
+    pub submission: Box<Account<'info, Submission>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitPaper<'info> {
+    pub fn submit_paper(&mut self, _id: u64, bump: u8) -> Result<()> {
+        require!(self.paper.is_active(), ErrorCode::PaperTakenDown);
+        //a paper is published in one journal at most
+        require!(self.paper.journal.is_none(), ErrorCode::AlreadyInJournal);
+
+        self.submission.set_inner(Submission {
+            journal: self.journal.key(),
+            paper: self.paper.key(),
+            paper_version: self.paper.version,
+            submitter: self.owner.key(),
+            status: SubmissionStatus::Pending,
+            editor: None,
+            decision_uri: None,
+            submitted_at: Clock::get()?.unix_timestamp as u64,
+            decided_at: 0,
+            bump,
+        });
+
+        self.journal.submissions = self.journal.submissions.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+}
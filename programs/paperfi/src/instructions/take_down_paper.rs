@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Report, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::constants::APPEAL_WINDOW_SECONDS;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct TakeDownPaper<'info> {
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    #[account(seeds = [b"role", moderator.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    #[account(
+        mut,
+        seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+        bump = paper.bump
+    )]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        seeds = [b"report", report.reporter.as_ref(), paper.key().as_ref()],
+        bump = report.bump
+    )]
+    pub report: Box<Account<'info, Report>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TakeDownPaper<'info> {
+    pub fn take_down_paper(&mut self, _id: u64) -> Result<()> {
+        authorize(&self.config, &self.moderator.key(), self.role_grant.as_deref(), Role::Moderator)?;
+        require!(self.report.paper == self.paper.key(), ErrorCode::ReportMismatch);
+        require!(self.report.status == ReportStatus::Open, ErrorCode::ReportAlreadyResolved);
+
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        let report = &mut self.report;
+        report.status = ReportStatus::Upheld;
+        report.moderator = Some(self.moderator.key());
+        report.resolved_at = now;
+
+        let appeal_deadline = now.checked_add(APPEAL_WINDOW_SECONDS).ok_or(ErrorCode::MathOverflow)?;
+        self.paper.take_down(self.moderator.key(), report.key(), now, appeal_deadline);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ReviewerStake;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    //Closing returns the stake and the rent
+    #[account(mut, close = owner, seeds = [b"stake", owner.key().as_ref()], bump = stake.bump)]
+    pub stake: Account<'info, ReviewerStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Unstake<'info> {
+    pub fn unstake(&mut self) -> Result<()> {
+        require!(self.stake.unlock_at != 0, ErrorCode::UnstakeNotRequested);
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(now >= self.stake.unlock_at, ErrorCode::StakeLocked);
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ReviewerPool;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateReviewerPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        seeds = [b"reviewer_pool", authority.key().as_ref(), pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, ReviewerPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateReviewerPool<'info> {
+    //Adds and removes reviewers in one call, removals first
+    pub fn update_reviewer_pool(&mut self, add: Vec<Pubkey>, remove: Vec<Pubkey>) -> Result<()> {
+        let pool = &mut self.pool;
+        pool.reviewers.retain(|reviewer| !remove.contains(reviewer));
+
+        for reviewer in add {
+            if !pool.reviewers.contains(&reviewer) {
+                pool.reviewers.push(reviewer);
+            }
+        }
+        require!(pool.reviewers.len() <= ReviewerPool::MAX_REVIEWERS, ErrorCode::TooManyReviewers);
+
+        pool.timestamp = Clock::get()?.unix_timestamp as u64;
+
+        Ok(())
+    }
+}
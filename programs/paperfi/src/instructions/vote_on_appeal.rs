@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{ Paper, Appeal, AppealVote, BoardMember, PaperFiConfig, RoleGrant };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct VoteOnAppeal<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Box<Account<'info, PaperFiConfig>>,
+
+    //Moderators vote through their role, panel members through their board membership
+    #[account(seeds = [b"role", voter.key().as_ref()], bump = role_grant.bump)]
+    pub role_grant: Option<Account<'info, RoleGrant>>,
+
+    pub board_member: Option<Box<Account<'info, BoardMember>>>,
+
+    #[account(mut, seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()], bump = paper.bump)]
+    pub paper: Box<Account<'info, Paper>>,
+
+    #[account(
+        mut,
+        has_one = paper @ ErrorCode::Unauthorized,
+        seeds = [b"appeal", paper.key().as_ref(), appeal.paper_version.to_le_bytes().as_ref()],
+        bump = appeal.bump
+    )]
+    pub appeal: Box<Account<'info, Appeal>>,
+
+    #[account(seeds = [b"author", voter.key().as_ref(), paper.key().as_ref()], bump)]
+    /// CHECKED : Must be empty, authors can't vote on the appeal of their own paper
+    pub voter_author: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VoteOnAppeal<'info> {
+    pub fn vote_on_appeal(&mut self, _id: u64, overturn: bool) -> Result<()> {
+        let voter = self.voter.key();
+        let moderator = authorize(&self.config, &voter, self.role_grant.as_deref(), Role::Moderator).is_ok();
+        let on_panel = self.board_member
+            .as_ref()
+            .is_some_and(|member| Some(member.board) == self.appeal.panel && member.member == voter);
+        require!(moderator || on_panel, ErrorCode::Unauthorized);
+
+        //board members and journal authorities can be authors of the paper too
+        require!(voter != self.paper.owner, ErrorCode::EditorIsAuthor);
+        require!(self.voter_author.data_is_empty(), ErrorCode::EditorIsAuthor);
+
+        let appeal = &mut self.appeal;
+        require!(appeal.status == AppealStatus::Open, ErrorCode::NoPendingAppeal);
+        require!(voter != appeal.appellant, ErrorCode::Unauthorized);
+        require!(!appeal.has_voted(&voter), ErrorCode::AlreadyVoted);
+        //the last seat is kept for a moderator
+        require!(
+            moderator || appeal.panel_votes() < Appeal::MAX_VOTES - 1,
+            ErrorCode::ModeratorVoteRequired
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        appeal.votes.push(AppealVote { voter, overturn, moderator, timestamp: now });
+
+        match appeal.outcome() {
+            Some(true) => {
+                appeal.status = AppealStatus::Overturned;
+                appeal.decided_at = now;
+                //only restore the listing the reviews took away, and only for the appealed version
+                let paper = &self.paper;
+                if paper.is_active() && paper.delisted_by_reviews && paper.version == appeal.paper_version {
+                    self.paper.restore_listing();
+                }
+            }
+            Some(false) => {
+                appeal.status = AppealStatus::Upheld;
+                appeal.decided_at = now;
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ UserAccount, Paper, Review, ReviewResponse, PaperReviewer, PaperFiConfig };
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(_id: u64)]
+pub struct WithdrawReview<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"user", signer.key().as_ref()],
+      bump = reviewer_user_account.bump
+    )]
+    pub reviewer_user_account: Account<'info, UserAccount>,
+
+    #[account(
+    mut,
+    seeds = [b"paper", paper.owner.as_ref(), &_id.to_le_bytes()],
+    bump = paper.bump
+)]
+    pub paper: Account<'info, Paper>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            b"review",
+            signer.key().as_ref(),
+            paper.key().as_ref(),
+            review.paper_version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    //Response to the review, closed with it when the owner or an author replied
+    #[account(mut, seeds = [b"response", review.key().as_ref()], bump)]
+    /// CHECK: may not exist, deserialized in the instruction
+    pub response: UncheckedAccount<'info>,
+
+    //Gets the rent of the response back
+    #[account(mut)]
+    /// CHECK: checked against the responder stored in the response
+    pub responder: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"paper_reviewer", signer.key().as_ref(), paper.key().as_ref()],
+        bump = paper_reviewer.bump
+    )]
+    pub paper_reviewer: Account<'info, PaperReviewer>,
+
+    #[account(seeds = [b"paperfi_config"], bump = config.bump)]
+    pub config: Account<'info, PaperFiConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawReview<'info> {
+    pub fn withdraw_review(&mut self, _id: u64) -> Result<()> {
+        require!(!self.review.rewarded, ErrorCode::ReviewRewarded);
+        //reviews of older versions are part of the version archive
+        require!(self.review.paper_version == self.paper.version, ErrorCode::ReviewVersionStale);
+
+        let time = Clock::get()?.unix_timestamp as u64;
+        let user = &mut self.reviewer_user_account;
+        if self.paper_reviewer.remove() {
+            //the remaining reviews must still cover the best review badge printed
+            require!(user.reviews > user.review_badge_record, ErrorCode::ReviewUsedForBadge);
+            user.reviews -= 1;
+        } else {
+            user.version_reviews = user.version_reviews.saturating_sub(1);
+        }
+        user.timestamp = time;
+
+        //Roll back what the review added and re-run the listing policy
+        let review = &self.review;
+        self.paper.remove_review(
+            &review.verdict,
+            review.weight,
+            review.scores.as_ref(),
+            &self.config.review_policy,
+            time
+        )?;
+
+        //Fees shared while the review stood are paid out, the review leaves the pool with it
+        let pool = &mut self.paper.reward_pool;
+        let amount = pool.pending(self.review.reward_debt);
+        if amount > 0 {
+            pool.claimed = pool.claimed.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+            **self.paper.to_account_info().try_borrow_mut_lamports()? -= amount;
+            **self.signer.to_account_info().try_borrow_mut_lamports()? += amount;
+        }
+
+        //A response without its review is closed and refunded to the responder
+        if !self.response.data_is_empty() {
+            let response = ReviewResponse::try_deserialize(&mut &self.response.data.borrow()[..])?;
+            let responder = self.responder.as_ref().ok_or(ErrorCode::ResponderMismatch)?;
+            require_keys_eq!(responder.key(), response.responder, ErrorCode::ResponderMismatch);
+
+            let response_info = self.response.to_account_info();
+            let rent = response_info.lamports();
+            **response_info.try_borrow_mut_lamports()? -= rent;
+            **responder.to_account_info().try_borrow_mut_lamports()? += rent;
+            response_info.assign(&System::id());
+            response_info.realloc(0, false)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ Journal, Submission };
+use crate::errors::ErrorCode;
+use crate::helpers::*;
+
+#[derive(Accounts)]
+pub struct WithdrawSubmission<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    #[account(seeds = [b"journal", journal.authority.as_ref(), journal.name.as_bytes()], bump = journal.bump)]
+    pub journal: Box<Account<'info, Journal>>,
+
+    //Closing lets the paper be submitted again or elsewhere
+    #[account(
+        mut,
+        close = submitter,
+        has_one = journal @ ErrorCode::Unauthorized,
+        has_one = submitter @ ErrorCode::Unauthorized,
+        seeds = [b"submission", journal.key().as_ref(), submission.paper.as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Box<Account<'info, Submission>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawSubmission<'info> {
+    pub fn withdraw_submission(&mut self) -> Result<()> {
+        //accepted papers stay in the journal listing
+        require!(self.submission.status != SubmissionStatus::Accepted, ErrorCode::SubmissionDecided);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod state;
@@ -21,6 +22,18 @@ pub mod paperfi {
         Ok(())
     }
 
+    //Admin adds another admin
+    pub fn add_admin(context: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
+        context.accounts.add_admin(new_admin)?;
+        Ok(())
+    }
+
+    //Admin removes another admin, one always remains
+    pub fn remove_admin(context: Context<RemoveAdmin>, old_admin: Pubkey) -> Result<()> {
+        context.accounts.remove_admin(old_admin)?;
+        Ok(())
+    }
+
     //Create new User
     pub fn signup(context: Context<NewUser>, name: String, title: String) -> Result<()> {
         context.accounts.new_user(name, title, context.bumps)?;
@@ -48,7 +61,7 @@ pub mod paperfi {
     //Edit Paper Info
     pub fn edit_paper(context: Context<EditPaper>, _id: u64, params: EditPaperParams) -> Result<()> {
         //key value
-        context.accounts.edit_paper(_id, params)?;
+        context.accounts.edit_paper(_id, params, context.bumps.paper_version)?;
         Ok(())
     }
 
@@ -69,9 +82,18 @@ pub mod paperfi {
         context: Context<ReviewPaper>,
         _id: u64,
         verdict: Verdict,
-        uri: String
+        uri: String,
+        scores: Option<ReviewScores>,
+        disclosure: Option<String>
     ) -> Result<()> {
-        context.accounts.review_paper(_id, verdict, uri)?;
+        context.accounts.review_paper(
+            _id,
+            verdict,
+            uri,
+            scores,
+            disclosure,
+            context.bumps.paper_reviewer
+        )?;
         Ok(())
     }
 
@@ -87,6 +109,11 @@ pub mod paperfi {
         Ok(())
     }
 
+    //Paper sales analytics (read only)
+    pub fn paper_sales(context: Context<PaperSales>, _id: u64) -> Result<SalesStats> {
+        context.accounts.paper_sales(_id)
+    }
+
     //User withdraw funds generated
     pub fn user_withdraw(context: Context<UserWithdraw>, vault_bump: u8) -> Result<()> {
         context.accounts.user_withdraw(vault_bump)?;
@@ -99,6 +126,479 @@ pub mod paperfi {
         Ok(())
     }
 
+    //Set the allowed URI schemes and gateways
+    pub fn set_uri_policy(context: Context<SetUriPolicy>, rules: Vec<UriRule>) -> Result<()> {
+        context.accounts.set_uri_policy(rules)?;
+        Ok(())
+    }
+
+    //Grant a role to a wallet
+    pub fn grant_role(context: Context<GrantRole>, wallet: Pubkey, role: Role) -> Result<()> {
+        context.accounts.grant_role(wallet, role, &context.bumps)?;
+        Ok(())
+    }
+
+    //Revoke a role from a wallet
+    pub fn revoke_role(context: Context<RevokeRole>, role: Role) -> Result<()> {
+        context.accounts.revoke_role(role)?;
+        Ok(())
+    }
+
+    //Change the platform fee
+    pub fn set_fee(context: Context<SetFee>, fee: u8) -> Result<()> {
+        context.accounts.set_fee(fee)?;
+        Ok(())
+    }
+
+    //Report a paper for moderation
+    pub fn report_paper(
+        context: Context<ReportPaper>,
+        _id: u64,
+        category: ReportCategory,
+        evidence_uri: String
+    ) -> Result<()> {
+        context.accounts.report_paper(_id, category, evidence_uri, context.bumps.report)?;
+        Ok(())
+    }
+
+    //Moderator dismisses a report
+    pub fn dismiss_report(context: Context<DismissReport>) -> Result<()> {
+        context.accounts.dismiss_report()?;
+        Ok(())
+    }
+
+    //Moderator upholds a report and takes the paper down
+    pub fn take_down_paper(context: Context<TakeDownPaper>, _id: u64) -> Result<()> {
+        context.accounts.take_down_paper(_id)?;
+        Ok(())
+    }
+
+    //Owner appeals a takedown
+    pub fn appeal_takedown(
+        context: Context<AppealTakedown>,
+        _id: u64,
+        appeal_uri: String
+    ) -> Result<()> {
+        context.accounts.appeal_takedown(_id, appeal_uri)?;
+        Ok(())
+    }
+
+    //Moderator decides on a takedown appeal
+    pub fn resolve_takedown_appeal(
+        context: Context<ResolveTakedownAppeal>,
+        _id: u64,
+        overturn: bool
+    ) -> Result<()> {
+        context.accounts.resolve_takedown_appeal(_id, overturn)?;
+        Ok(())
+    }
+
+    //Set the review quorum and rejection threshold
+    pub fn set_review_policy(context: Context<SetReviewPolicy>, policy: ReviewPolicy) -> Result<()> {
+        context.accounts.set_review_policy(policy)?;
+        Ok(())
+    }
+
+    //Moderator decides on a paper flagged by its reviews
+    pub fn resolve_review_flag(
+        context: Context<ResolveReviewFlag>,
+        _id: u64,
+        delist: bool
+    ) -> Result<()> {
+        context.accounts.resolve_review_flag(_id, delist)?;
+        Ok(())
+    }
+
+    //Owner opens a commit-reveal review round
+    pub fn open_review_round(
+        context: Context<OpenReviewRound>,
+        _id: u64,
+        commit_duration: u64,
+        reveal_duration: u64
+    ) -> Result<()> {
+        context.accounts.open_review_round(_id, commit_duration, reveal_duration)?;
+        Ok(())
+    }
+
+    //Reviewer commits to a hidden review during the commit phase
+    pub fn commit_review(
+        context: Context<CommitReview>,
+        _id: u64,
+        commitment: [u8; 32]
+    ) -> Result<()> {
+        context.accounts.commit_review(_id, commitment, context.bumps.commitment)?;
+        Ok(())
+    }
+
+    //Reviewer reveals the committed review during the reveal phase
+    pub fn reveal_review(
+        context: Context<RevealReview>,
+        _id: u64,
+        verdict: Verdict,
+        uri: String,
+        scores: Option<ReviewScores>,
+        salt: [u8; 32],
+        disclosure: Option<String>
+    ) -> Result<()> {
+        context.accounts.reveal_review(
+            _id,
+            verdict,
+            uri,
+            scores,
+            salt,
+            disclosure,
+            context.bumps.paper_reviewer
+        )?;
+        Ok(())
+    }
+
+    //Close a commitment that was not revealed in time
+    pub fn expire_commitment(context: Context<ExpireCommitment>, _id: u64) -> Result<()> {
+        context.accounts.expire_commitment(_id)?;
+        Ok(())
+    }
+
+    //Paper owner or verified author responds to a review
+    pub fn respond_to_review(
+        context: Context<RespondToReview>,
+        _id: u64,
+        uri: String,
+        linked_version: Option<u32>
+    ) -> Result<()> {
+        context.accounts.respond_to_review(_id, uri, linked_version, context.bumps.response)?;
+        Ok(())
+    }
+
+    //Reviewer acknowledges the response, optionally changing the verdict
+    pub fn acknowledge_response(
+        context: Context<AcknowledgeResponse>,
+        _id: u64,
+        verdict: Option<Verdict>
+    ) -> Result<()> {
+        context.accounts.acknowledge_response(_id, verdict)?;
+        Ok(())
+    }
+
+    //Reviewer withdraws a review, rolling back its counters
+    pub fn withdraw_review(context: Context<WithdrawReview>, _id: u64) -> Result<()> {
+        context.accounts.withdraw_review(_id)?;
+        Ok(())
+    }
+
+    //Paper owner or verified author sets the reviewers excluded from the paper
+    pub fn set_conflicts(
+        context: Context<SetConflicts>,
+        _id: u64,
+        wallets: Vec<Pubkey>,
+        affiliations: Vec<String>
+    ) -> Result<()> {
+        context.accounts.set_conflicts(_id, wallets, affiliations, context.bumps.conflicts)?;
+        Ok(())
+    }
+
+    //Create an editorial board, the signer manages its members
+    pub fn create_board(context: Context<CreateBoard>, name: String) -> Result<()> {
+        context.accounts.create_board(name, context.bumps.board)?;
+        Ok(())
+    }
+
+    //Board authority adds a member
+    pub fn add_board_member(context: Context<AddBoardMember>, member: Pubkey) -> Result<()> {
+        context.accounts.add_board_member(member, context.bumps.board_member)?;
+        Ok(())
+    }
+
+    //Board authority removes a member
+    pub fn remove_board_member(context: Context<RemoveBoardMember>) -> Result<()> {
+        context.accounts.remove_board_member()?;
+        Ok(())
+    }
+
+    //Owner sets the requirements reviewers of the paper must meet
+    pub fn set_paper_requirements(
+        context: Context<SetPaperRequirements>,
+        _id: u64,
+        requirements: Option<ReviewerRequirements>
+    ) -> Result<()> {
+        context.accounts.set_paper_requirements(_id, requirements)?;
+        Ok(())
+    }
+
+    //Platform wide requirements for every reviewer
+    pub fn set_platform_requirements(
+        context: Context<SetPlatformRequirements>,
+        requirements: ReviewerRequirements
+    ) -> Result<()> {
+        context.accounts.set_platform_requirements(requirements)?;
+        Ok(())
+    }
+
+    //Owner or verified author escrows rewards for the reviewers of a paper
+    pub fn fund_bounty(
+        context: Context<FundBounty>,
+        _id: u64,
+        payout: u64,
+        max_reviews: u32,
+        duration: u64
+    ) -> Result<()> {
+        context.accounts.fund_bounty(_id, payout, max_reviews, duration, &context.bumps)?;
+        Ok(())
+    }
+
+    //Funder takes back what is left of an expired or spent bounty
+    pub fn refund_bounty(context: Context<RefundBounty>, _id: u64) -> Result<()> {
+        context.accounts.refund_bounty(_id)?;
+        Ok(())
+    }
+
+    //Share of each sale fee routed to the paper reviewers
+    pub fn set_reviewer_fee_share(context: Context<SetReviewerFeeShare>, share_bps: u16) -> Result<()> {
+        context.accounts.set_reviewer_fee_share(share_bps)?;
+        Ok(())
+    }
+
+    //Reviewer claims its share of the paper reward pool
+    pub fn claim_review_rewards(context: Context<ClaimReviewRewards>, _id: u64) -> Result<()> {
+        context.accounts.claim_review_rewards(_id)?;
+        Ok(())
+    }
+
+    //Reviewer locks lamports to be eligible to review
+    pub fn stake(context: Context<Stake>, amount: u64) -> Result<()> {
+        context.accounts.stake(amount, context.bumps.stake)?;
+        Ok(())
+    }
+
+    //Reviewer starts the unstake cooldown
+    pub fn request_unstake(context: Context<RequestUnstake>) -> Result<()> {
+        context.accounts.request_unstake()?;
+        Ok(())
+    }
+
+    //Reviewer takes the stake back after the cooldown
+    pub fn unstake(context: Context<Unstake>) -> Result<()> {
+        context.accounts.unstake()?;
+        Ok(())
+    }
+
+    //Report an abusive review
+    pub fn report_review(
+        context: Context<ReportReview>,
+        _id: u64,
+        category: ReportCategory,
+        evidence_uri: String
+    ) -> Result<()> {
+        context.accounts.report_review(_id, category, evidence_uri, context.bumps.report)?;
+        Ok(())
+    }
+
+    //Moderator upholds a review report and slashes the reviewer stake
+    pub fn slash_stake(context: Context<SlashStake>, amount: u64, to_author: bool) -> Result<()> {
+        context.accounts.slash_stake(amount, to_author)?;
+        Ok(())
+    }
+
+    //Minimum stake required to review
+    pub fn set_min_stake(context: Context<SetMinStake>, min_stake: u64) -> Result<()> {
+        context.accounts.set_min_stake(min_stake)?;
+        Ok(())
+    }
+
+    //Paying reader rates a review as helpful or unhelpful
+    pub fn rate_review(context: Context<RateReview>, _id: u64, helpful: bool) -> Result<()> {
+        context.accounts.rate_review(_id, helpful, context.bumps.rating)?;
+        Ok(())
+    }
+
+    //Create a journal edited by the members of an editorial board
+    pub fn create_journal(context: Context<CreateJournal>, name: String) -> Result<()> {
+        context.accounts.create_journal(name, context.bumps.journal)?;
+        Ok(())
+    }
+
+    //Paper owner submits the paper to a journal
+    pub fn submit_paper(context: Context<SubmitPaper>, _id: u64) -> Result<()> {
+        context.accounts.submit_paper(_id, context.bumps.submission)?;
+        Ok(())
+    }
+
+    //Journal editor accepts or rejects a submission
+    pub fn decide_submission(
+        context: Context<DecideSubmission>,
+        accept: bool,
+        decision_uri: Option<String>
+    ) -> Result<()> {
+        context.accounts.decide_submission(accept, decision_uri)?;
+        Ok(())
+    }
+
+    //Submitter withdraws a pending or rejected submission
+    pub fn withdraw_submission(context: Context<WithdrawSubmission>) -> Result<()> {
+        context.accounts.withdraw_submission()?;
+        Ok(())
+    }
+
+    //Paper owner or journal editor invites a reviewer hidden behind a commitment
+    pub fn invite_reviewer(
+        context: Context<InviteReviewer>,
+        _id: u64,
+        reviewer_commitment: [u8; 32],
+        review_key: Pubkey,
+        deadline: u64
+    ) -> Result<()> {
+        context.accounts.invite_reviewer(
+            _id,
+            reviewer_commitment,
+            review_key,
+            deadline,
+            context.bumps.invitation
+        )?;
+        Ok(())
+    }
+
+    //Invited reviewer seals a review with the review key, without buying the paper
+    pub fn review_invited(
+        context: Context<ReviewInvited>,
+        _id: u64,
+        verdict: Verdict,
+        uri: String,
+        scores: Option<ReviewScores>
+    ) -> Result<()> {
+        context.accounts.review_invited(_id, verdict, uri, scores)?;
+        Ok(())
+    }
+
+    //Invited reviewer reveals the sealed review once the decision is published
+    pub fn reveal_invited_review(
+        context: Context<RevealInvitedReview>,
+        _id: u64,
+        salt: [u8; 32]
+    ) -> Result<()> {
+        context.accounts.reveal_invited_review(_id, salt, context.bumps.paper_reviewer)?;
+        Ok(())
+    }
+
+    //Inviter hands an expired invitation to another reviewer
+    pub fn reassign_invitation(
+        context: Context<ReassignInvitation>,
+        _id: u64,
+        reviewer_commitment: [u8; 32],
+        review_key: Pubkey,
+        deadline: u64
+    ) -> Result<()> {
+        context.accounts.reassign_invitation(
+            _id,
+            reviewer_commitment,
+            review_key,
+            deadline,
+            context.bumps.invitation
+        )?;
+        Ok(())
+    }
+
+    //Anyone marks an invitation past its deadline as missed
+    pub fn expire_review_assignment(
+        context: Context<ExpireReviewAssignment>,
+        _id: u64,
+        salt: Option<[u8; 32]>
+    ) -> Result<()> {
+        context.accounts.expire_review_assignment(_id, salt)?;
+        Ok(())
+    }
+
+    //Create a reviewer pool for a journal or a field
+    pub fn create_reviewer_pool(context: Context<CreateReviewerPool>, name: String) -> Result<()> {
+        context.accounts.create_reviewer_pool(name, context.bumps.pool)?;
+        Ok(())
+    }
+
+    //Pool authority adds and removes reviewers
+    pub fn update_reviewer_pool(
+        context: Context<UpdateReviewerPool>,
+        add: Vec<Pubkey>,
+        remove: Vec<Pubkey>
+    ) -> Result<()> {
+        context.accounts.update_reviewer_pool(add, remove)?;
+        Ok(())
+    }
+
+    //Pool authority draws reviewers for a paper from recent slot hashes
+    pub fn draw_reviewers<'info>(
+        context: Context<'_, '_, '_, 'info, DrawReviewers<'info>>,
+        _id: u64,
+        count: u8
+    ) -> Result<()> {
+        context.accounts.draw_reviewers(_id, count, context.remaining_accounts, context.bumps.draw)?;
+        Ok(())
+    }
+
+    //Paper owner appeals a delisting caused by the reviews
+    pub fn appeal_delisting(
+        context: Context<AppealDelisting>,
+        _id: u64,
+        rationale_uri: String
+    ) -> Result<()> {
+        context.accounts.appeal_delisting(_id, rationale_uri, context.bumps.appeal)?;
+        Ok(())
+    }
+
+    //Moderator or panel member votes to uphold or overturn a delisting
+    pub fn vote_on_appeal(context: Context<VoteOnAppeal>, _id: u64, overturn: bool) -> Result<()> {
+        context.accounts.vote_on_appeal(_id, overturn)?;
+        Ok(())
+    }
+
+    //Paper owner disputes the priority or originality of another paper
+    pub fn open_dispute(
+        context: Context<OpenDispute>,
+        _id: u64,
+        respondent_id: u64,
+        category: DisputeCategory,
+        evidence_uri: String
+    ) -> Result<()> {
+        context.accounts.open_dispute(
+            _id,
+            respondent_id,
+            category,
+            evidence_uri,
+            context.bumps.dispute
+        )?;
+        Ok(())
+    }
+
+    //Claimant or respondent submits a statement
+    pub fn submit_dispute_statement(
+        context: Context<SubmitDisputeStatement>,
+        statement_uri: String
+    ) -> Result<()> {
+        context.accounts.submit_dispute_statement(statement_uri)?;
+        Ok(())
+    }
+
+    //Arbitrators vote on the dispute ruling, the quorum on the same side decides
+    pub fn rule_dispute(
+        context: Context<RuleDispute>,
+        upheld: bool,
+        take_down: bool,
+        redirect_proceeds: bool,
+        ruling_uri: String
+    ) -> Result<()> {
+        context.accounts.rule_dispute(upheld, take_down, redirect_proceeds, ruling_uri)?;
+        Ok(())
+    }
+
+    //Votes on the same side that decide the disputes opened from now on
+    pub fn set_dispute_quorum(context: Context<SetDisputeQuorum>, quorum: u8) -> Result<()> {
+        context.accounts.set_dispute_quorum(quorum)?;
+        Ok(())
+    }
+
+    //Claimant or arbitrator gives the sales of a paper back to its owner
+    pub fn lift_proceeds_redirect(context: Context<LiftProceedsRedirect>, _id: u64) -> Result<()> {
+        context.accounts.lift_proceeds_redirect(_id)?;
+        Ok(())
+    }
+
     //Create a collection asset 
     pub fn make_badge(context: Context<MakeBadge>, args: CreateBadgeArgs) -> Result<()> {
         context.accounts.make_badge(args)?;
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::helpers::{ AppealStatus, EVIDENCE_URI };
+
+//Appeal of a review-driven delisting, the votes are kept as the audit trail
+#[account]
+pub struct Appeal {
+    pub paper: Pubkey,
+    pub paper_version: u32, // Version whose reviews delisted the paper
+    pub appellant: Pubkey,
+    pub rationale_uri: String,
+    pub panel: Option<Pubkey>, // Editorial board of the paper journal, moderators can always vote
+    pub votes: Vec<AppealVote>, // Max 5, decided at 2 on the same side with a moderator on that side
+    pub status: AppealStatus,
+    pub opened_at: u64,
+    pub decided_at: u64,
+    pub bump: u8,
+}
+
+impl Space for Appeal {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // paper (Pubkey)
+        4 + // paper_version (u32)
+        32 + // appellant (Pubkey)
+        (EVIDENCE_URI.max_bytes + 4) + // rationale_uri (max 200 bytes + prefix)
+        (1 + 32) + // panel (Option<Pubkey>)
+        (4 + Self::MAX_VOTES * AppealVote::INIT_SPACE) + // votes vec
+        1 + // status (enum)
+        8 + // opened_at (u64)
+        8 + // decided_at (u64)
+        1; // bump (u8)
+}
+
+impl Appeal {
+    pub const MAX_VOTES: usize = 5;
+    pub const VOTES_TO_DECIDE: usize = 2;
+
+    pub fn has_voted(&self, voter: &Pubkey) -> bool {
+        self.votes.iter().any(|vote| vote.voter == *voter)
+    }
+
+    pub fn panel_votes(&self) -> usize {
+        self.votes.iter().filter(|vote| !vote.moderator).count()
+    }
+
+    //Some(overturn) once a side has enough votes with a moderator among them, the panel alone can't decide.
+    //When every seat is taken and no moderator sided with a majority, the delisting stands
+    pub fn outcome(&self) -> Option<bool> {
+        let decides = |overturn: bool| {
+            let side = || self.votes.iter().filter(move |vote| vote.overturn == overturn);
+            side().count() >= Self::VOTES_TO_DECIDE && side().any(|vote| vote.moderator)
+        };
+        if decides(true) {
+            Some(true)
+        } else if decides(false) || self.votes.len() >= Self::MAX_VOTES {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AppealVote {
+    pub voter: Pubkey,
+    pub overturn: bool,
+    pub moderator: bool, // Voted through the Moderator role rather than the panel
+    pub timestamp: u64,
+}
+
+impl anchor_lang::Space for AppealVote {
+    const INIT_SPACE: usize = 32 + 1 + 1 + 8;
+}
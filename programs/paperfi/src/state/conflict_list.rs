@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::helpers::{ validate_text, USER_AFFILIATION };
+use crate::state::UserAccount;
+
+//Reviewers the authors exclude from reviewing a paper.
+//Only the wallet list is authoritative: affiliations are self declared by reviewers,
+//so they only catch reviewers who state theirs (current or previous one)
+#[account]
+pub struct ConflictList {
+    pub paper: Pubkey,
+    pub excluded_wallets: Vec<Pubkey>,
+    pub excluded_affiliations: Vec<String>, // e.g. the authors institution
+    pub updated_by: Pubkey,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ConflictList {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // paper (Pubkey)
+        4 + Self::MAX_WALLETS * 32 + // excluded_wallets vec
+        4 + Self::MAX_AFFILIATIONS * (USER_AFFILIATION.max_bytes + 4) + // excluded_affiliations vec
+        32 + // updated_by (Pubkey)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ConflictList {
+    pub const MAX_WALLETS: usize = 10;
+    pub const MAX_AFFILIATIONS: usize = 5;
+
+    pub fn validate(wallets: &[Pubkey], affiliations: &[String]) -> Result<()> {
+        require!(wallets.len() <= Self::MAX_WALLETS, ErrorCode::TooManyConflicts);
+        require!(affiliations.len() <= Self::MAX_AFFILIATIONS, ErrorCode::TooManyConflicts);
+        for affiliation in affiliations {
+            validate_text(affiliation, &USER_AFFILIATION)?;
+        }
+        Ok(())
+    }
+
+    pub fn excludes(&self, reviewer: &UserAccount) -> bool {
+        self.excluded_wallets.contains(&reviewer.owner) ||
+            [&reviewer.affiliation, &reviewer.previous_affiliation].iter().any(|declared| {
+                !declared.is_empty() &&
+                    self.excluded_affiliations
+                        .iter()
+                        .any(|affiliation| affiliation.eq_ignore_ascii_case(declared))
+            })
+    }
+
+    //The list is passed unchecked so reviewers can't skip it, papers without a list have no exclusions
+    pub fn load(conflicts: &AccountInfo) -> Result<Option<ConflictList>> {
+        if conflicts.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ConflictList::try_deserialize(&mut &conflicts.data.borrow()[..])?))
+    }
+
+    pub fn check(conflicts: &AccountInfo, reviewer: &UserAccount) -> Result<()> {
+        if let Some(list) = Self::load(conflicts)? {
+            require!(!list.excludes(reviewer), ErrorCode::ConflictOfInterest);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::helpers::{ DisputeCategory, DisputeStatus, EVIDENCE_URI };
+
+//Claim that the respondent paper copies or predates the claimant paper unfairly
+#[account]
+pub struct Dispute {
+    pub claimant: Pubkey,
+    pub claimant_paper: Pubkey,
+    pub respondent: Pubkey, // Owner of the respondent paper when the dispute was opened
+    pub respondent_paper: Pubkey,
+    pub category: DisputeCategory,
+    pub evidence_uri: String,
+    pub claimant_statement: Option<String>,
+    pub respondent_statement: Option<String>,
+    pub status: DisputeStatus,
+    pub arbitrator: Option<Pubkey>, // Cast the deciding vote
+    pub ruling_uri: Option<String>,
+    pub votes: Vec<DisputeVote>, // Max 3
+    pub quorum: u8, // Votes on the same side that decide, from the config when the dispute was opened
+    pub taken_down: bool, // Ruling took the respondent paper down
+    pub proceeds_redirected: bool, // Ruling sent the respondent paper sales to the claimant
+    pub opened_at: u64,
+    pub ruled_at: u64,
+    pub bump: u8,
+}
+
+impl Space for Dispute {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // claimant (Pubkey)
+        32 + // claimant_paper (Pubkey)
+        32 + // respondent (Pubkey)
+        32 + // respondent_paper (Pubkey)
+        1 + // category (enum)
+        (EVIDENCE_URI.max_bytes + 4) + // evidence_uri (max 200 bytes + prefix)
+        (1 + 4 + EVIDENCE_URI.max_bytes) + // claimant_statement (Option<String>)
+        (1 + 4 + EVIDENCE_URI.max_bytes) + // respondent_statement (Option<String>)
+        1 + // status (enum)
+        (1 + 32) + // arbitrator (Option<Pubkey>)
+        (1 + 4 + EVIDENCE_URI.max_bytes) + // ruling_uri (Option<String>)
+        (4 + Self::MAX_VOTES * DisputeVote::INIT_SPACE) + // votes vec
+        1 + // quorum (u8)
+        1 + // taken_down (bool)
+        1 + // proceeds_redirected (bool)
+        8 + // opened_at (u64)
+        8 + // ruled_at (u64)
+        1; // bump (u8)
+}
+
+impl Dispute {
+    pub const MAX_VOTES: usize = 3;
+    //A majority of the full panel, so the votes can't split without a ruling
+    pub const MAX_QUORUM: u8 = 2;
+
+    pub fn has_voted(&self, arbitrator: &Pubkey) -> bool {
+        self.votes.iter().any(|vote| vote.arbitrator == *arbitrator)
+    }
+
+    //Some(ruling) once one side has enough votes. An upheld ruling only carries
+    //the remedies every upholding arbitrator asked for
+    pub fn outcome(&self) -> Option<DisputeRuling> {
+        let upheld: Vec<&DisputeVote> = self.votes.iter().filter(|vote| vote.upheld).collect();
+        let quorum = self.quorum as usize;
+        if upheld.len() >= quorum {
+            Some(DisputeRuling {
+                upheld: true,
+                take_down: upheld.iter().all(|vote| vote.take_down),
+                redirect_proceeds: upheld.iter().all(|vote| vote.redirect_proceeds),
+            })
+        } else if self.votes.len() - upheld.len() >= quorum {
+            Some(DisputeRuling { upheld: false, take_down: false, redirect_proceeds: false })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeVote {
+    pub arbitrator: Pubkey,
+    pub upheld: bool,
+    pub take_down: bool,
+    pub redirect_proceeds: bool,
+    pub timestamp: u64,
+}
+
+impl anchor_lang::Space for DisputeVote {
+    const INIT_SPACE: usize = 32 + 1 + 1 + 1 + 8;
+}
+
+pub struct DisputeRuling {
+    pub upheld: bool,
+    pub take_down: bool,
+    pub redirect_proceeds: bool,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::helpers::BOARD_NAME;
+
+#[account]
+pub struct EditorialBoard {
+    pub authority: Pubkey, // Manages the members
+    pub name: String,
+    pub members: u32,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for EditorialBoard {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // authority (Pubkey)
+        (BOARD_NAME.max_bytes + 4) + // name (max 32 bytes + prefix)
+        4 + // members (u32)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+#[account]
+pub struct BoardMember {
+    pub board: Pubkey,
+    pub member: Pubkey,
+    pub added_at: u64,
+    pub bump: u8,
+}
+
+impl Space for BoardMember {
+    const INIT_SPACE: usize = 8 + 32 + 32 + 8 + 1;
+}
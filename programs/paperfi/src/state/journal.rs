@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::helpers::{ JOURNAL_NAME, REVIEW_URI, SubmissionStatus };
+
+//Curated venue, its editors are the members of its editorial board
+#[account]
+pub struct Journal {
+    pub authority: Pubkey, // Also manages the board
+    pub name: String,
+    pub board: Pubkey,
+    pub submissions: u32,
+    pub accepted: u32, // Papers listed in the journal
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for Journal {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // authority (Pubkey)
+        (JOURNAL_NAME.max_bytes + 4) + // name (max 32 bytes + prefix)
+        32 + // board (Pubkey)
+        4 + // submissions (u32)
+        4 + // accepted (u32)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+//Link between a paper and a journal, accepted submissions make the journal listing
+#[account]
+pub struct Submission {
+    pub journal: Pubkey,
+    pub paper: Pubkey,
+    pub paper_version: u32, // Version submitted
+    pub submitter: Pubkey,
+    pub status: SubmissionStatus,
+    pub editor: Option<Pubkey>, // Editor who decided
+    pub decision_uri: Option<String>, // Decision letter
+    pub submitted_at: u64,
+    pub decided_at: u64,
+    pub bump: u8,
+}
+
+impl Space for Submission {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // journal (Pubkey)
+        32 + // paper (Pubkey)
+        4 + // paper_version (u32)
+        32 + // submitter (Pubkey)
+        1 + // status (enum)
+        (1 + 32) + // editor (Option<Pubkey>)
+        (1 + 4 + REVIEW_URI.max_bytes) + // decision_uri (Option<String>, max 200 bytes + prefix)
+        8 + // submitted_at (u64)
+        8 + // decided_at (u64)
+        1; // bump (u8)
+}
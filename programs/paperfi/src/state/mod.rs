@@ -4,6 +4,22 @@ pub mod user_account;
 pub mod paper_owned;
 pub mod paperfi_config;
 pub mod paper_author;
+pub mod role_grant;
+pub mod report;
+pub mod review_commitment;
+pub mod review_response;
+pub mod paper_version;
+pub mod conflict_list;
+pub mod editorial_board;
+pub mod review_bounty;
+pub mod reviewer_stake;
+pub mod review_rating;
+pub mod journal;
+pub mod review_invitation;
+pub mod reviewer_pool;
+pub mod appeal;
+pub mod dispute;
+pub mod paper_reviewer;
 
 pub use paper::*;
 pub use review::*;
@@ -11,3 +27,19 @@ pub use user_account::*;
 pub use paper_owned::*;
 pub use paperfi_config::*;
 pub use paper_author::*;
+pub use role_grant::*;
+pub use report::*;
+pub use review_commitment::*;
+pub use review_response::*;
+pub use paper_version::*;
+pub use conflict_list::*;
+pub use editorial_board::*;
+pub use review_bounty::*;
+pub use reviewer_stake::*;
+pub use review_rating::*;
+pub use journal::*;
+pub use review_invitation::*;
+pub use reviewer_pool::*;
+pub use appeal::*;
+pub use dispute::*;
+pub use paper_reviewer::*;
@@ -1,5 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::helpers::Verdict;
+use crate::helpers::{ Verdict, ModerationStatus };
+use crate::errors::ErrorCode;
+use mpl_core::accounts::BaseAssetV1;
+use crate::state::{ ReviewPolicy, ReviewScores, ReviewerRequirements, UserAccount, BoardMember };
+use crate::constants::{
+    SALES_PERIOD_SECONDS,
+    SALES_PERIODS,
+    MAX_SALE_CURRENCIES,
+    REWARD_PRECISION,
+};
+
 #[account]
 pub struct Paper {
     pub paper_info_url: String,
@@ -9,11 +19,24 @@ pub struct Paper {
     pub price: u64,
     pub bump: u8,
     pub user_bump: u8,
-    pub reviews: u32,
-    pub review_status: ReviewStatus,
+    pub reviews: u32, // All versions
+    pub review_status: ReviewStatus, // Current version only, older tallies live in PaperVersion
     pub sales: u32,
     pub timestamp: u64,
     pub paper_uri: String,
+    pub sales_stats: SalesStats,
+    pub moderation: Moderation,
+    pub delisted_by_reviews: bool, // Set when the listing policy unlisted the paper
+    pub flagged_by_reviews: bool, // Set when the rejection threshold is crossed without auto delisting
+    pub score_summary: ScoreSummary,
+    pub review_round: Option<ReviewRound>, // Commit-reveal round, reviews only land at reveal
+    pub version_reviews: u32, // Reviews of the current version
+    pub reviewer_requirements: Option<ReviewerRequirements>, // Set by the owner on top of the platform ones
+    pub reward_pool: RewardPool, // Lamports are held by the paper account itself
+    pub journal: Option<Pubkey>, // Journal that accepted the paper
+    pub delisting_overturned: bool, // An appeal overturned the review delisting of the current version
+    pub overturned_rejections: i64, // Rejections counted when the appeal overturned the delisting
+    pub proceeds_recipient: Option<Pubkey>, // Set by a dispute ruling, sales pay this wallet instead of the owner
 }
 
 impl Space for Paper {
@@ -30,7 +53,283 @@ impl Space for Paper {
         ReviewStatus::INIT_SPACE + // review_status struct
         4 + // sales (u32)
         8 + // timestamp (u64)
-        (200 + 4); //URI (max 200 chars + prefix)
+        (200 + 4) + //URI (max 200 chars + prefix)
+        SalesStats::INIT_SPACE + // sales_stats struct
+        Moderation::INIT_SPACE + // moderation struct
+        1 + // delisted_by_reviews (bool)
+        1 + // flagged_by_reviews (bool)
+        ScoreSummary::INIT_SPACE + // score_summary struct
+        1 + ReviewRound::INIT_SPACE + // review_round (Option<ReviewRound>)
+        4 + // version_reviews (u32)
+        1 + ReviewerRequirements::INIT_SPACE + // reviewer_requirements (Option<ReviewerRequirements>)
+        RewardPool::INIT_SPACE + // reward_pool struct
+        (1 + 32) + // journal (Option<Pubkey>)
+        1 + // delisting_overturned (bool)
+        8 + // overturned_rejections (i64)
+        (1 + 32); // proceeds_recipient (Option<Pubkey>)
+}
+
+impl Paper {
+    //Taken down and appealed papers can't be sold or reviewed
+    pub fn is_active(&self) -> bool {
+        self.moderation.status == ModerationStatus::Active
+    }
+
+    //Wallet whose vault receives the sales
+    pub fn payee(&self) -> Pubkey {
+        self.proceeds_recipient.unwrap_or(self.owner)
+    }
+
+    //A paper already taken down keeps its original decision and appeal window, false when nothing changed
+    pub fn take_down(&mut self, moderator: Pubkey, reason: Pubkey, now: u64, appeal_deadline: u64) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+        self.moderation.listed_before = self.listed;
+        self.listed = false;
+        self.moderation.status = ModerationStatus::TakenDown;
+        self.moderation.report = Some(reason);
+        self.moderation.moderator = Some(moderator);
+        self.moderation.decided_at = now;
+        self.moderation.appeal_deadline = appeal_deadline;
+        self.moderation.appeal_uri = None;
+        true
+    }
+
+    //While a round is open reviews go through commit and reveal
+    pub fn in_review_round(&self, now: u64) -> bool {
+        self.review_round.as_ref().is_some_and(|round| now < round.reveal_deadline)
+    }
+
+    //Platform requirements first, then the ones set by the owner
+    pub fn check_reviewer(
+        &self,
+        platform: &ReviewerRequirements,
+        reviewer: &UserAccount,
+        now: u64,
+        badge_asset: Option<&BaseAssetV1>,
+        board_member: Option<&BoardMember>
+    ) -> Result<()> {
+        platform.check(reviewer, now, badge_asset, board_member)?;
+        if let Some(requirements) = &self.reviewer_requirements {
+            requirements.check(reviewer, now, badge_asset, board_member)?;
+        }
+        Ok(())
+    }
+
+    //Counts a new review, used by direct reviews and by reveals
+    pub fn add_review(
+        &mut self,
+        verdict: &Verdict,
+        weight: u64,
+        scores: Option<&ReviewScores>,
+        policy: &ReviewPolicy,
+        now: u64
+    ) -> Result<()> {
+        self.reviews += 1;
+        self.version_reviews += 1;
+        self.timestamp = now;
+        self.review_status.update(verdict, weight)?;
+        if let Some(scores) = scores {
+            self.score_summary.add(scores)?;
+        }
+        self.apply_listing_policy(policy);
+        Ok(())
+    }
+
+    //Reverts add_review when a review is withdrawn
+    pub fn remove_review(
+        &mut self,
+        verdict: &Verdict,
+        weight: u64,
+        scores: Option<&ReviewScores>,
+        policy: &ReviewPolicy,
+        now: u64
+    ) -> Result<()> {
+        self.reviews = self.reviews.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+        self.version_reviews = self.version_reviews.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+        self.timestamp = now;
+        self.review_status.remove(verdict, weight)?;
+        if let Some(scores) = scores {
+            self.score_summary.remove(scores)?;
+        }
+        self.apply_listing_policy(policy);
+        Ok(())
+    }
+
+    //Moves the current version tallies out and starts the new version from zero
+    pub fn start_version(&mut self, version: u32, policy: &ReviewPolicy) {
+        self.version = version;
+        self.version_reviews = 0;
+        self.review_status = ReviewStatus::default();
+        self.score_summary = ScoreSummary::default();
+        self.delisting_overturned = false;
+        self.overturned_rejections = 0;
+        self.apply_listing_policy(policy);
+    }
+
+    //Appeal overturned, the reviews of this version can't delist the paper again
+    pub fn restore_listing(&mut self) {
+        self.listed = true;
+        self.delisted_by_reviews = false;
+        self.flagged_by_reviews = false;
+        self.delisting_overturned = true;
+        self.overturned_rejections = self.review_status.rejected;
+    }
+
+    //Single listing policy for every change to the review status.
+    //Papers unlisted by their owner or by a moderator are left alone,
+    //papers unlisted by this policy come back once the rejections are acceptable again.
+    pub fn apply_listing_policy(&mut self, policy: &ReviewPolicy) {
+        if !self.is_active() {
+            return;
+        }
+
+        if !self.review_status.exceeds_rejection_threshold(policy) {
+            self.flagged_by_reviews = false;
+            if self.delisted_by_reviews {
+                self.listed = true;
+                self.delisted_by_reviews = false;
+            }
+            return;
+        }
+
+        //the appeal only covers the reviews it saw, a new quorum of rejections applies again
+        if self.delisting_overturned {
+            let new_rejections = self.review_status.rejected.saturating_sub(self.overturned_rejections);
+            if new_rejections < (policy.min_quorum as i64) {
+                return;
+            }
+            self.delisting_overturned = false;
+        }
+
+        if policy.auto_delist {
+            if self.listed {
+                self.listed = false;
+                self.delisted_by_reviews = true;
+            }
+        } else if !self.delisted_by_reviews {
+            self.flagged_by_reviews = true;
+        }
+    }
+
+    //Moves a review from the old verdict bucket to the new one and re-runs the listing policy
+    pub fn apply_verdict_change(
+        &mut self,
+        old: &Verdict,
+        new: &Verdict,
+        weight: u64,
+        policy: &ReviewPolicy
+    ) -> Result<()> {
+        self.review_status.remove(old, weight)?;
+        self.review_status.update(new, weight)?;
+        self.apply_listing_policy(policy);
+        Ok(())
+    }
+}
+
+//Aggregated structured scores: novelty, methodology, reproducibility, clarity, significance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ScoreSummary {
+    pub count: u32, // Reviews with scores
+    pub totals: [u32; ReviewScores::CRITERIA],
+    pub averages: [u16; ReviewScores::CRITERIA], // x100, 350 = 3.50
+}
+
+impl anchor_lang::Space for ScoreSummary {
+    const INIT_SPACE: usize = 4 + 4 * ReviewScores::CRITERIA + 2 * ReviewScores::CRITERIA;
+}
+
+impl ScoreSummary {
+    pub fn add(&mut self, scores: &ReviewScores) -> Result<()> {
+        self.count = self.count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        for (total, score) in self.totals.iter_mut().zip(scores.as_array()) {
+            *total = total.checked_add(score as u32).ok_or(ErrorCode::MathOverflow)?;
+        }
+        self.update_averages();
+        Ok(())
+    }
+
+    pub fn remove(&mut self, scores: &ReviewScores) -> Result<()> {
+        self.count = self.count.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+        for (total, score) in self.totals.iter_mut().zip(scores.as_array()) {
+            *total = total.checked_sub(score as u32).ok_or(ErrorCode::MathOverflow)?;
+        }
+        self.update_averages();
+        Ok(())
+    }
+
+    fn update_averages(&mut self) {
+        for (average, total) in self.averages.iter_mut().zip(self.totals) {
+            *average = match self.count {
+                0 => 0,
+                count => ((total * 100) / count) as u16, // max 500
+            };
+        }
+    }
+}
+
+//Reviewer share of the sale fees, split equally between the reviews held at the time of each sale
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RewardPool {
+    pub deposited: u64,
+    pub claimed: u64,
+    pub acc_per_review: u128, // Lamports per review since the paper was created, x REWARD_PRECISION
+}
+
+impl anchor_lang::Space for RewardPool {
+    const INIT_SPACE: usize = 8 + 8 + 16;
+}
+
+impl RewardPool {
+    pub fn deposit(&mut self, amount: u64, reviews: u32) -> Result<()> {
+        require!(reviews > 0, ErrorCode::MathOverflow);
+        self.deposited = self.deposited.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        self.acc_per_review = self.acc_per_review
+            .checked_add(((amount as u128) * REWARD_PRECISION) / (reviews as u128))
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    //Amount owed to a review that already collected up to `reward_debt`
+    pub fn pending(&self, reward_debt: u128) -> u64 {
+        (self.acc_per_review.saturating_sub(reward_debt) / REWARD_PRECISION) as u64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReviewRound {
+    pub commit_deadline: u64, // Commitments accepted until then
+    pub reveal_deadline: u64, // Reveals accepted from the commit deadline until then
+    pub commitments: u32,
+    pub revealed: u32,
+    pub expired: u32,
+}
+
+impl anchor_lang::Space for ReviewRound {
+    const INIT_SPACE: usize = 8 + 8 + 4 + 4 + 4;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Moderation {
+    pub status: ModerationStatus,
+    pub report: Option<Pubkey>, // Report or dispute that led to the takedown
+    pub moderator: Option<Pubkey>, // Moderator of the last decision
+    pub decided_at: u64,
+    pub appeal_deadline: u64,
+    pub appeal_uri: Option<String>,
+    pub listed_before: bool, // Listing to restore if the takedown is overturned
+}
+
+impl anchor_lang::Space for Moderation {
+    const INIT_SPACE: usize =
+        1 + // status (enum)
+        (1 + 32) + // report (Option<Pubkey>)
+        (1 + 32) + // moderator (Option<Pubkey>)
+        8 + // decided_at (u64)
+        8 + // appeal_deadline (u64)
+        (1 + 200 + 4) + // appeal_uri (Option<String>, max 200 chars + prefix)
+        1; // listed_before (bool)
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -38,38 +337,150 @@ pub struct ReviewStatus {
     pub approved: i64,
     pub rejected: i64,
     pub review_requested: i64,
+    pub weighted_approved: u64, // Sum of the reviewers reputation weights per verdict
+    pub weighted_rejected: u64,
+    pub weighted_review_requested: u64,
 }
 
 impl anchor_lang::Space for ReviewStatus {
-    const INIT_SPACE: usize = 8 * 3; // Three u32 fields (each 4 bytes)
+    const INIT_SPACE: usize = 8 * 6; // Three i64 counters and three u64 weighted totals
 }
 
 impl ReviewStatus {
-    pub fn update(&mut self, verdict: &Verdict) {
+    fn buckets(&mut self, verdict: &Verdict) -> (&mut i64, &mut u64) {
         match verdict {
-            Verdict::Approved => {
-                self.approved += 1;
-            }
-            Verdict::Rejected => {
-                self.rejected += 1;
-            }
+            Verdict::Approved => (&mut self.approved, &mut self.weighted_approved),
+            Verdict::Rejected => (&mut self.rejected, &mut self.weighted_rejected),
             Verdict::ReviewRequested => {
-                self.review_requested += 1;
+                (&mut self.review_requested, &mut self.weighted_review_requested)
             }
         }
     }
 
-    pub fn rejection_ratio(&self) -> i64 {
-        let total_reviews = self.rejected + self.approved + self.review_requested;
+    pub fn update(&mut self, verdict: &Verdict, weight: u64) -> Result<()> {
+        let (count, weighted) = self.buckets(verdict);
+        *count = count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        *weighted = weighted.checked_add(weight).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, verdict: &Verdict, weight: u64) -> Result<()> {
+        let (count, weighted) = self.buckets(verdict);
+        require!(*count > 0, ErrorCode::MathOverflow);
+        *count -= 1;
+        *weighted = weighted.checked_sub(weight).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    //Reviews counted by the policy
+    pub fn total(&self, policy: &ReviewPolicy) -> i64 {
+        let mut total = self.approved + self.rejected;
+        if policy.count_review_requested {
+            total += self.review_requested;
+        }
+        total
+    }
+
+    //Reputation weight counted by the policy
+    pub fn weighted_total(&self, policy: &ReviewPolicy) -> u64 {
+        let mut total = self.weighted_approved.saturating_add(self.weighted_rejected);
+        if policy.count_review_requested {
+            total = total.saturating_add(self.weighted_review_requested);
+        }
+        total
+    }
+
+    //Weighted rejections in basis points of the counted reviews, 0 when there are none
+    pub fn rejection_bps(&self, policy: &ReviewPolicy) -> u64 {
+        let total = self.weighted_total(policy);
+        if total == 0 {
+            return 0;
+        }
+        ((self.weighted_rejected as u128) * 10_000 / (total as u128)) as u64
+    }
+
+    //The quorum counts reviews, the threshold uses the weighted figures.
+    //Integer only: rejected / total > threshold  <=>  rejected * 10000 > threshold * total
+    pub fn exceeds_rejection_threshold(&self, policy: &ReviewPolicy) -> bool {
+        if self.total(policy) < (policy.min_quorum as i64) {
+            return false;
+        }
+        (self.weighted_rejected as u128) * 10_000 >
+            (policy.rejection_threshold_bps as u128) * (self.weighted_total(policy) as u128)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CurrencyRevenue {
+    pub currency: Pubkey, // system program id for native SOL, mint address otherwise
+    pub revenue: u64, // gross amount paid to the paper owner
+    pub fees: u64, // fees paid to PaperFi
+}
+
+impl anchor_lang::Space for CurrencyRevenue {
+    const INIT_SPACE: usize = 32 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SalesStats {
+    pub revenue: Vec<CurrencyRevenue>, // Max MAX_SALE_CURRENCIES entries
+    pub first_sale: u64,
+    pub last_sale: u64,
+    pub current_period: u64, // index of the 30-day period of the last sale
+    pub period_sales: [u32; SALES_PERIODS], // rolling buckets, indexed by period % SALES_PERIODS
+}
 
-        if total_reviews == 0 {
-            return 0; // Return 0 if there are no reviews
+impl anchor_lang::Space for SalesStats {
+    const INIT_SPACE: usize =
+        4 + MAX_SALE_CURRENCIES * CurrencyRevenue::INIT_SPACE + // revenue vec
+        8 + // first_sale (u64)
+        8 + // last_sale (u64)
+        8 + // current_period (u64)
+        4 * SALES_PERIODS; // period_sales ([u32; SALES_PERIODS])
+}
+
+impl SalesStats {
+    pub fn record_sale(&mut self, currency: Pubkey, revenue: u64, fees: u64, now: u64) -> Result<()> {
+        match self.revenue.iter_mut().find(|entry| entry.currency == currency) {
+            Some(entry) => {
+                entry.revenue = entry.revenue.checked_add(revenue).ok_or(ErrorCode::MathOverflow)?;
+                entry.fees = entry.fees.checked_add(fees).ok_or(ErrorCode::MathOverflow)?;
+            }
+            None => {
+                require!(self.revenue.len() < MAX_SALE_CURRENCIES, ErrorCode::TooManyCurrencies);
+                self.revenue.push(CurrencyRevenue { currency, revenue, fees });
+            }
+        }
+
+        if self.first_sale == 0 {
+            self.first_sale = now;
         }
+        self.last_sale = now;
 
-        // Use floating-point division to handle the fraction or it will return 0 because it is an integer
-        let ratio = (self.rejected as f64) / (total_reviews as f64);
+        //move the window before counting the sale in the current bucket
+        self.roll_periods(now);
+        let bucket = (self.current_period % (SALES_PERIODS as u64)) as usize;
+        self.period_sales[bucket] = self.period_sales[bucket].saturating_add(1);
 
-        // Multiply by 100 to get percentage
-        (ratio * 100.0) as i64
+        Ok(())
+    }
+
+    //Moves the rolling window up to the period of `now`, clearing the buckets of the periods without sales
+    pub fn roll_periods(&mut self, now: u64) {
+        let period = now / SALES_PERIOD_SECONDS;
+
+        if period <= self.current_period {
+            return;
+        }
+
+        let elapsed = period - self.current_period;
+        if elapsed >= (SALES_PERIODS as u64) {
+            self.period_sales = [0; SALES_PERIODS];
+        } else {
+            for p in self.current_period + 1..=period {
+                self.period_sales[(p % (SALES_PERIODS as u64)) as usize] = 0;
+            }
+        }
+        self.current_period = period;
     }
 }
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+//Reviews a wallet holds on a paper across its versions, so the paper only counts once on the reviewer profile
+#[account]
+pub struct PaperReviewer {
+    pub reviewer: Pubkey,
+    pub paper: Pubkey,
+    pub reviews: u32, // Standing reviews, one per version at most
+    pub bump: u8,
+}
+
+impl Space for PaperReviewer {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // reviewer (Pubkey)
+        32 + // paper (Pubkey)
+        4 + // reviews (u32)
+        1; // bump (u8)
+}
+
+impl PaperReviewer {
+    //True for the first standing review of the paper, later versions are re-reviews
+    pub fn add(&mut self, reviewer: Pubkey, paper: Pubkey, bump: u8) -> bool {
+        self.reviewer = reviewer;
+        self.paper = paper;
+        self.bump = bump;
+        self.reviews += 1;
+        self.reviews == 1
+    }
+
+    //True once the last standing review of the paper is gone
+    pub fn remove(&mut self) -> bool {
+        self.reviews = self.reviews.saturating_sub(1);
+        self.reviews == 0
+    }
+}
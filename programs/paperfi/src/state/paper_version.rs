@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::{ ReviewStatus, ScoreSummary };
+
+//Review tallies of a paper version, archived when the owner publishes the next one
+#[account]
+pub struct PaperVersion {
+    pub paper: Pubkey,
+    pub version: u32,
+    pub paper_uri: String, // Content the reviews judged
+    pub reviews: u32,
+    pub review_status: ReviewStatus,
+    pub score_summary: ScoreSummary,
+    pub archived_at: u64,
+    pub bump: u8,
+}
+
+impl Space for PaperVersion {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // paper (Pubkey)
+        4 + // version (u32)
+        (200 + 4) + // paper_uri (max 200 chars + prefix)
+        4 + // reviews (u32)
+        ReviewStatus::INIT_SPACE + // review_status struct
+        ScoreSummary::INIT_SPACE + // score_summary struct
+        8 + // archived_at (u64)
+        1; // bump (u8)
+}
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use mpl_core::{ accounts::BaseAssetV1, types::UpdateAuthority };
+use crate::errors::ErrorCode;
+use crate::state::{ UserAccount, BoardMember };
 
 #[account]
 pub struct PaperFiConfig {
@@ -6,9 +9,186 @@ pub struct PaperFiConfig {
     pub fee: Option<u8>, //to be able to check if it is set
     pub bump: u8,
     pub vault_bump: u8,
+    pub uri_policy: UriPolicy,
+    pub review_policy: ReviewPolicy,
+    pub reviewer_requirements: ReviewerRequirements, // Platform wide, papers can add their own
+    pub reviewer_fee_share_bps: u16, // Share of each sale fee paid to the paper reviewers
+    pub min_reviewer_stake: u64, // Lamports a reviewer must have staked, 0 disables staking
+    pub dispute_quorum: u8, // Arbitrator votes on the same side that decide a dispute
 }
 
 impl PaperFiConfig {
     pub const MAX_ADMINS: usize = 3;
-    pub const INIT_SPACE: usize = 8 + Self::MAX_ADMINS * 32 + 1 + 1 + 1; // 8 (discriminator) + 3 * pubkey (32) + 1 fee + 1 bump + 1 bump;
+    pub const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        (4 + Self::MAX_ADMINS * 32) + // admins (max 3 pubkeys + prefix)
+        (1 + 1) + // fee (Option<u8>)
+        1 + // bump (u8)
+        1 + // vault_bump (u8)
+        UriPolicy::INIT_SPACE + // uri_policy struct
+        ReviewPolicy::INIT_SPACE + // review_policy struct
+        ReviewerRequirements::INIT_SPACE + // reviewer_requirements struct
+        2 + // reviewer_fee_share_bps (u16)
+        8 + // min_reviewer_stake (u64)
+        1; // dispute_quorum (u8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UriRule {
+    pub scheme: String, // e.g. "ar", "ipfs", "https"
+    pub host: Option<String>, // None accepts any host for the scheme
+}
+
+impl UriRule {
+    pub const MAX_SCHEME_LEN: usize = 10;
+    pub const MAX_HOST_LEN: usize = 64;
+
+    pub fn new(scheme: &str, host: Option<&str>) -> Self {
+        UriRule {
+            scheme: scheme.to_string(),
+            host: host.map(|host| host.to_string()),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.scheme.is_empty() && self.scheme.len() <= Self::MAX_SCHEME_LEN,
+            ErrorCode::InvalidFieldLength
+        );
+        require!(
+            self.scheme
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '+' || c == '-' || c == '.'),
+            ErrorCode::MalformedUri
+        );
+
+        if let Some(host) = &self.host {
+            require!(
+                !host.is_empty() && host.len() <= Self::MAX_HOST_LEN,
+                ErrorCode::InvalidFieldLength
+            );
+            require!(
+                host.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.'),
+                ErrorCode::MalformedUri
+            );
+        }
+        Ok(())
+    }
+}
+
+impl anchor_lang::Space for UriRule {
+    const INIT_SPACE: usize =
+        (4 + Self::MAX_SCHEME_LEN) + // scheme (max 10 chars + prefix)
+        (1 + 4 + Self::MAX_HOST_LEN); // host (Option<String>, max 64 chars + prefix)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UriPolicy {
+    pub rules: Vec<UriRule>, // Max 8 rules, empty only checks that URIs are well formed
+}
+
+impl UriPolicy {
+    pub const MAX_RULES: usize = 8;
+
+    //Arweave and IPFS natively or through their public https gateways
+    pub fn default_rules() -> Vec<UriRule> {
+        vec![
+            UriRule::new("ar", None),
+            UriRule::new("ipfs", None),
+            UriRule::new("https", Some("arweave.net")),
+            UriRule::new("https", Some("ipfs.io"))
+        ]
+    }
+
+    pub fn set_rules(&mut self, rules: Vec<UriRule>) -> Result<()> {
+        require!(rules.len() <= Self::MAX_RULES, ErrorCode::TooManyUriRules);
+        for rule in rules.iter() {
+            rule.validate()?;
+        }
+        self.rules = rules;
+        Ok(())
+    }
+}
+
+impl anchor_lang::Space for UriPolicy {
+    const INIT_SPACE: usize = 4 + Self::MAX_RULES * UriRule::INIT_SPACE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReviewPolicy {
+    pub min_quorum: u32, // Reviews needed before the rejection threshold applies
+    pub rejection_threshold_bps: u16, // Max share of rejections in basis points
+    pub count_review_requested: bool, // Whether ReviewRequested verdicts count in the total
+    pub auto_delist: bool, // Delist automatically or flag the paper for moderators
+}
+
+impl Default for ReviewPolicy {
+    fn default() -> Self {
+        ReviewPolicy {
+            min_quorum: 3,
+            rejection_threshold_bps: 2_000, // 20%
+            count_review_requested: true,
+            auto_delist: true,
+        }
+    }
+}
+
+impl anchor_lang::Space for ReviewPolicy {
+    const INIT_SPACE: usize = 4 + 2 + 1 + 1;
+}
+
+impl ReviewPolicy {
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_quorum > 0, ErrorCode::InvalidReviewPolicy);
+        require!(self.rejection_threshold_bps <= Self::MAX_BPS, ErrorCode::InvalidReviewPolicy);
+        Ok(())
+    }
+}
+
+//Zero and None fields are not required
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ReviewerRequirements {
+    pub min_account_age: u64, // Seconds since signup
+    pub min_papers: u32, // Published papers
+    pub badge_collection: Option<Pubkey>, // Reviewer must hold an asset of this collection
+    pub editorial_board: Option<Pubkey>, // Reviewer must be a member of this board
+}
+
+impl anchor_lang::Space for ReviewerRequirements {
+    const INIT_SPACE: usize = 8 + 4 + (1 + 32) + (1 + 32);
+}
+
+impl ReviewerRequirements {
+    pub fn check(
+        &self,
+        reviewer: &UserAccount,
+        now: u64,
+        badge_asset: Option<&BaseAssetV1>,
+        board_member: Option<&BoardMember>
+    ) -> Result<()> {
+        require!(
+            now.saturating_sub(reviewer.created_at) >= self.min_account_age,
+            ErrorCode::AccountTooNew
+        );
+        require!(reviewer.papers >= self.min_papers, ErrorCode::NotEnoughPapers);
+
+        if let Some(collection) = self.badge_collection {
+            let holds_badge = badge_asset.is_some_and(|asset| {
+                asset.owner == reviewer.owner &&
+                    asset.update_authority == UpdateAuthority::Collection(collection)
+            });
+            require!(holds_badge, ErrorCode::MissingRequiredBadge);
+        }
+
+        if let Some(board) = self.editorial_board {
+            let is_member = board_member.is_some_and(
+                |member| member.board == board && member.member == reviewer.owner
+            );
+            require!(is_member, ErrorCode::NotBoardMember);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::helpers::{ ReportCategory, ReportStatus };
+
+#[account]
+pub struct Report {
+    pub reporter: Pubkey,
+    pub paper: Pubkey,
+    pub review: Option<Pubkey>, // Set when the report targets a review of the paper
+    pub reviewer: Option<Pubkey>, // Author of the reported review, kept if the review is withdrawn
+    pub category: ReportCategory,
+    pub evidence_uri: String,
+    pub status: ReportStatus,
+    pub moderator: Option<Pubkey>, // Moderator that resolved the report
+    pub resolved_at: u64,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for Report {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // reporter (Pubkey)
+        32 + // paper (Pubkey)
+        (1 + 32) + // review (Option<Pubkey>)
+        (1 + 32) + // reviewer (Option<Pubkey>)
+        1 + // category (enum)
+        (200 + 4) + // evidence_uri (max 200 chars + prefix)
+        1 + // status (enum)
+        (1 + 32) + // moderator (Option<Pubkey>)
+        8 + // resolved_at (u64)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl Report {
+    //Second seed of the report, the review for review reports and the paper otherwise
+    pub fn target(&self) -> Pubkey {
+        self.review.unwrap_or(self.paper)
+    }
+}
@@ -1,17 +1,141 @@
 use anchor_lang::prelude::*;
 use crate::helpers::Verdict;
+use crate::errors::ErrorCode;
 
 #[account]
 pub struct Review {
     pub owner: Pubkey,
     pub paper: Pubkey,
+    pub paper_version: u32, // Paper version this review judged
     pub verdict: Verdict,
     pub timestamp: u64,
     pub review_uri: String,
+    pub edits: u16,
+    pub history: Vec<VerdictChange>, // Max 5 prior verdicts, oldest dropped first
+    pub weight: u64, // Reviewer reputation weight when the review was written
+    pub scores: Option<ReviewScores>,
+    pub rewarded: bool, // Set once a reward was paid for this review
+    pub declared_conflict: Option<String>, // Disclosed by the reviewer
+    pub reward_debt: u128, // Paper reward accumulator already paid or not owed to this review
+    pub rewards_claimed: u64, // Fee share claimed so far
+    pub helpful_votes: u32, // Ratings by paying readers
+    pub unhelpful_votes: u32,
 }
 
 impl Space for Review {
-    const INIT_SPACE: usize = 8 + 32 + 32 + Verdict::INIT_SPACE + 8 + 204;
+    const INIT_SPACE: usize =
+        8 + 32 + 32 + 4 + Verdict::INIT_SPACE + 8 + 204 +
+        2 + // edits (u16)
+        4 + Self::MAX_HISTORY * VerdictChange::INIT_SPACE + // history vec
+        8 + // weight (u64)
+        1 + ReviewScores::INIT_SPACE + // scores (Option<ReviewScores>)
+        1 + // rewarded (bool)
+        (1 + 300 + 4) + // declared_conflict (Option<String>, max 100 chars / 300 bytes + prefix)
+        16 + // reward_debt (u128)
+        8 + // rewards_claimed (u64)
+        4 + // helpful_votes (u32)
+        4; // unhelpful_votes (u32)
+}
+
+impl Review {
+    pub const MAX_HISTORY: usize = 5;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        owner: Pubkey,
+        paper: Pubkey,
+        paper_version: u32,
+        verdict: Verdict,
+        review_uri: String,
+        weight: u64,
+        scores: Option<ReviewScores>,
+        now: u64
+    ) -> Self {
+        Self {
+            owner,
+            paper,
+            paper_version,
+            verdict,
+            timestamp: now,
+            review_uri,
+            edits: 0,
+            history: Vec::new(),
+            weight,
+            scores,
+            rewarded: false,
+            declared_conflict: None,
+            reward_debt: 0,
+            rewards_claimed: 0,
+            helpful_votes: 0,
+            unhelpful_votes: 0,
+        }
+    }
+
+    //Stores the current verdict in the history and returns it
+    pub fn change_verdict(&mut self, verdict: Verdict, now: u64) -> Result<Verdict> {
+        require!(self.verdict != verdict, ErrorCode::VerdictUnchanged);
+
+        if self.history.len() == Self::MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(VerdictChange {
+            verdict: self.verdict,
+            timestamp: self.timestamp,
+        });
+
+        let old = self.verdict;
+        self.verdict = verdict;
+        self.timestamp = now;
+        self.edits = self.edits.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(old)
+    }
+}
+
+//Structured criteria, each on a 1 to 5 scale
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ReviewScores {
+    pub novelty: u8,
+    pub methodology: u8,
+    pub reproducibility: u8,
+    pub clarity: u8,
+    pub significance: u8,
+}
+
+impl anchor_lang::Space for ReviewScores {
+    const INIT_SPACE: usize = 5;
+}
+
+impl ReviewScores {
+    pub const CRITERIA: usize = 5;
+    pub const MIN_SCORE: u8 = 1;
+    pub const MAX_SCORE: u8 = 5;
+
+    //Same order as ScoreSummary totals and averages
+    pub fn as_array(&self) -> [u8; Self::CRITERIA] {
+        [self.novelty, self.methodology, self.reproducibility, self.clarity, self.significance]
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self
+                .as_array()
+                .iter()
+                .all(|score| (Self::MIN_SCORE..=Self::MAX_SCORE).contains(score)),
+            ErrorCode::InvalidScore
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerdictChange {
+    pub verdict: Verdict, // Prior verdict
+    pub timestamp: u64, // When the prior verdict was given
+}
+
+impl anchor_lang::Space for VerdictChange {
+    const INIT_SPACE: usize = Verdict::INIT_SPACE + 8;
 }
 
 /*
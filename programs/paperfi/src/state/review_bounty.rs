@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use anchor_spl::token::{ self, Token, TokenAccount };
+use crate::errors::ErrorCode;
+
+//Reviewer rewards escrowed by the authors of a paper
+#[account]
+pub struct ReviewBounty {
+    pub paper: Pubkey,
+    pub funder: Pubkey, // Refunded after expiry
+    pub mint: Option<Pubkey>, // None for native SOL
+    pub payout: u64, // Paid per accepted review
+    pub max_reviews: u32,
+    pub paid_reviews: u32,
+    pub reserved_reviews: u32, // Held for invited reviewers until they review or miss the deadline
+    pub expires_at: u64,
+    pub timestamp: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Space for ReviewBounty {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // paper (Pubkey)
+        32 + // funder (Pubkey)
+        (1 + 32) + // mint (Option<Pubkey>)
+        8 + // payout (u64)
+        4 + // max_reviews (u32)
+        4 + // paid_reviews (u32)
+        4 + // reserved_reviews (u32)
+        8 + // expires_at (u64)
+        8 + // timestamp (u64)
+        1 + // bump (u8)
+        1; // vault_bump (u8)
+}
+
+impl ReviewBounty {
+    //Open to reviewers without a reservation
+    pub fn is_open(&self, now: u64) -> bool {
+        now < self.expires_at && self.paid_reviews + self.reserved_reviews < self.max_reviews
+    }
+
+    //Nothing can be paid anymore, reserved reviews included
+    pub fn is_settled(&self, now: u64) -> bool {
+        now >= self.expires_at || self.paid_reviews >= self.max_reviews
+    }
+
+    pub fn reserve(&mut self, now: u64) -> Result<()> {
+        require!(self.is_open(now), ErrorCode::InvalidBounty);
+        self.reserved_reviews += 1;
+        Ok(())
+    }
+
+    pub fn release(&mut self) {
+        self.reserved_reviews = self.reserved_reviews.saturating_sub(1);
+    }
+
+    //Pays one review from the vault, the caller records it
+    pub fn pay<'info>(
+        bounty: &ReviewBounty,
+        bounty_info: &AccountInfo<'info>,
+        accounts: &BountyPayout<'_, 'info>
+    ) -> Result<()> {
+        let bounty_key = bounty_info.key();
+        let payout = bounty.payout;
+
+        match bounty.mint {
+            None => {
+                let vault = accounts.bounty_vault.ok_or(ErrorCode::MissingTokenAccounts)?;
+                let seeds = &[b"bounty_vault", bounty_key.as_ref(), &[bounty.vault_bump]];
+                let signer_seeds = &[&seeds[..]];
+                let vault_key = Pubkey::create_program_address(seeds, &crate::ID).map_err(
+                    |_| ErrorCode::BountyMismatch
+                )?;
+                require!(vault.key() == vault_key, ErrorCode::BountyMismatch);
+
+                let cpi_accounts = Transfer {
+                    from: vault.to_account_info(),
+                    to: accounts.reviewer.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    accounts.system_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds
+                );
+                transfer(cpi_ctx, payout)?;
+            }
+            Some(mint) => {
+                let (Some(vault), Some(to), Some(token_program)) = (
+                    accounts.bounty_token_vault,
+                    accounts.reviewer_token_account,
+                    accounts.token_program,
+                ) else {
+                    return Err(ErrorCode::MissingTokenAccounts.into());
+                };
+                require!(
+                    to.mint == mint && to.owner == accounts.reviewer.key(),
+                    ErrorCode::BountyMismatch
+                );
+
+                let seeds = &[b"bounty", bounty.paper.as_ref(), &[bounty.bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = token::Transfer {
+                    from: vault.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: bounty_info.clone(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds
+                );
+                token::transfer(cpi_ctx, payout)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    //Bounty holding the reservations of the paper invitations, passed unchecked so it can't be left out.
+    //A missing bounty was refunded, which only happens once every reservation was given back
+    pub fn load(bounty: &AccountInfo) -> Result<Option<ReviewBounty>> {
+        if bounty.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ReviewBounty::try_deserialize(&mut &bounty.data.borrow()[..])?))
+    }
+
+    pub fn store(&self, bounty: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut bounty.data.borrow_mut()[..])
+    }
+
+    //Escrow needed to pay every review
+    pub fn total(&self) -> Option<u64> {
+        self.payout.checked_mul(self.max_reviews as u64)
+    }
+}
+
+//Accounts a bounty payout goes through, the vaults can't be seeded from the optional bounty
+pub struct BountyPayout<'a, 'info> {
+    pub reviewer: &'a Signer<'info>,
+    pub bounty_vault: Option<&'a SystemAccount<'info>>,
+    pub bounty_token_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub reviewer_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub token_program: Option<&'a Program<'info, Token>>,
+    pub system_program: &'a Program<'info, System>,
+}
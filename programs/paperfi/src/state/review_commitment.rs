@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::helpers::Verdict;
+use crate::state::ReviewScores;
+
+#[account]
+pub struct ReviewCommitment {
+    pub reviewer: Pubkey,
+    pub paper: Pubkey,
+    pub commitment: [u8; 32], // sha256(reviewer || paper || version || verdict || scores || uri || salt)
+    pub reveal_deadline: u64, // Reveal deadline of the round it was made in
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewCommitment {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // reviewer (Pubkey)
+        32 + // paper (Pubkey)
+        32 + // commitment ([u8; 32])
+        8 + // reveal_deadline (u64)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ReviewCommitment {
+    //Clients hash the reviewer and paper keys, the paper version (4 bytes le), the verdict index (1 byte),
+    //the scores (0 when none, else 1 and the five scores), the uri bytes and a 32 byte salt,
+    //so a copied commitment can't be revealed by another reviewer or on another paper
+    pub fn hash(
+        reviewer: &Pubkey,
+        paper: &Pubkey,
+        version: u32,
+        verdict: Verdict,
+        scores: Option<&ReviewScores>,
+        uri: &str,
+        salt: &[u8; 32]
+    ) -> [u8; 32] {
+        let mut scores_bytes = [0u8; 1 + ReviewScores::CRITERIA];
+        let scores_len = match scores {
+            Some(scores) => {
+                scores_bytes[0] = 1;
+                scores_bytes[1..].copy_from_slice(&scores.as_array());
+                scores_bytes.len()
+            }
+            None => 1,
+        };
+        hashv(
+            &[
+                reviewer.as_ref(),
+                paper.as_ref(),
+                &version.to_le_bytes(),
+                &[verdict as u8],
+                &scores_bytes[..scores_len],
+                uri.as_bytes(),
+                salt,
+            ]
+        ).to_bytes()
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::helpers::{ InvitationStatus, SubmissionStatus, Verdict, REVIEW_URI };
+use crate::state::{ ReviewScores, Submission };
+use crate::constants::INVITED_REVEAL_SECONDS;
+
+//Invitation to review a paper. The reviewer stays hidden until the decision is published: only the commitment is
+//on chain, and the review is sealed in the invitation by a one-off review key the reviewer handed to the inviter
+//together with the salt. The wallet reveals itself afterwards, when the review is counted and paid
+#[account]
+pub struct ReviewInvitation {
+    pub paper: Pubkey,
+    pub inviter: Pubkey, // Paper owner or journal editor
+    pub journal: Option<Pubkey>, // Set when a journal editor invited
+    pub reviewer_commitment: [u8; 32], // sha256(reviewer || salt)
+    pub review_key: Pubkey, // Signs the sealed review, unlinked to the reviewer wallet
+    pub reviewer: Option<Pubkey>, // Revealed after the decision
+    pub review: Option<Pubkey>,
+    pub deadline: u64,
+    pub status: InvitationStatus,
+    pub bounty_reserved: bool, // Holds a slot of the paper bounty
+    pub verdict: Option<Verdict>, // Sealed review, set by the review key
+    pub uri: String,
+    pub scores: Option<ReviewScores>,
+    pub version: u32, // Paper version the sealed review judges
+    pub submitted_at: u64,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewInvitation {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // paper (Pubkey)
+        32 + // inviter (Pubkey)
+        (1 + 32) + // journal (Option<Pubkey>)
+        32 + // reviewer_commitment ([u8; 32])
+        32 + // review_key (Pubkey)
+        (1 + 32) + // reviewer (Option<Pubkey>)
+        (1 + 32) + // review (Option<Pubkey>)
+        8 + // deadline (u64)
+        1 + // status (enum)
+        1 + // bounty_reserved (bool)
+        (1 + 1) + // verdict (Option<enum>)
+        (4 + REVIEW_URI.max_bytes) + // uri (String)
+        (1 + ReviewScores::INIT_SPACE) + // scores (Option<ReviewScores>)
+        4 + // version (u32)
+        8 + // submitted_at (u64)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ReviewInvitation {
+    //Clients hash the reviewer wallet and a 32 byte salt shared privately with the reviewer
+    pub fn hash(reviewer: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[reviewer.as_ref(), salt]).to_bytes()
+    }
+
+    pub fn is_pending(&self, now: u64) -> bool {
+        self.status == InvitationStatus::Pending && now <= self.deadline
+    }
+
+    //The decision is published once the journal decided, or at the deadline for invitations of the owner.
+    //A journal that doesn't decide can't keep the review sealed for longer than INVITED_REVEAL_SECONDS past the deadline
+    pub fn reveal_opens(&self, submission: Option<&Submission>) -> u64 {
+        let fallback = self.deadline.saturating_add(INVITED_REVEAL_SECONDS);
+        match (self.journal, submission) {
+            (None, _) => self.deadline,
+            (Some(_), Some(submission)) if submission.status != SubmissionStatus::Pending =>
+                submission.decided_at.min(fallback),
+            _ => fallback,
+        }
+    }
+
+    //Sealed reviews not revealed by then expire, which leaves the reviewer at least INVITED_REVEAL_SECONDS
+    pub fn reveal_deadline(&self) -> u64 {
+        self.deadline.saturating_add(INVITED_REVEAL_SECONDS.saturating_mul(2))
+    }
+}
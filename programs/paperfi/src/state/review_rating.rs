@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+//One helpfulness vote per wallet and review
+#[account]
+pub struct ReviewRating {
+    pub review: Pubkey,
+    pub rater: Pubkey,
+    pub helpful: bool,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewRating {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // review (Pubkey)
+        32 + // rater (Pubkey)
+        1 + // helpful (bool)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
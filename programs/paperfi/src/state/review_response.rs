@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ReviewResponse {
+    pub review: Pubkey,
+    pub paper: Pubkey,
+    pub responder: Pubkey, // Paper owner or verified author
+    pub response_uri: String,
+    pub linked_version: Option<u32>, // Paper version addressing the review concerns
+    pub timestamp: u64,
+    pub acknowledged: bool, // Set by the reviewer
+    pub acknowledged_at: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewResponse {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // review (Pubkey)
+        32 + // paper (Pubkey)
+        32 + // responder (Pubkey)
+        (200 + 4) + // response_uri (max 200 chars + prefix)
+        (1 + 4) + // linked_version (Option<u32>)
+        8 + // timestamp (u64)
+        1 + // acknowledged (bool)
+        8 + // acknowledged_at (u64)
+        1; // bump (u8)
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::helpers::POOL_NAME;
+
+//Reviewers a journal or a field draws from
+#[account]
+pub struct ReviewerPool {
+    pub authority: Pubkey, // Manages the reviewers and runs the draws
+    pub name: String,
+    pub journal: Option<Pubkey>,
+    pub reviewers: Vec<Pubkey>, // Max 10
+    pub draws: u32,
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewerPool {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // authority (Pubkey)
+        (POOL_NAME.max_bytes + 4) + // name (max 32 bytes + prefix)
+        (1 + 32) + // journal (Option<Pubkey>)
+        (4 + Self::MAX_REVIEWERS * 32) + // reviewers (max 10 pubkeys + prefix)
+        4 + // draws (u32)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ReviewerPool {
+    //A draw passes up to five proof accounts per reviewer, ten reviewers stay under
+    //the 64 accounts a transaction can lock
+    pub const MAX_REVIEWERS: usize = 10;
+}
+
+//Result of a draw, anyone can replay it from the seed and the pool at that slot.
+//Keyed by the pool journal (the default key for field pools) and the paper, not by the pool
+#[account]
+pub struct ReviewerDraw {
+    pub pool: Pubkey,
+    pub paper: Pubkey,
+    pub drawn_by: Pubkey,
+    pub slot: u64, // Slot whose hash seeded the draw
+    pub seed: [u8; 32], // sha256(slot hash || pool || paper)
+    pub candidates: u32, // Eligible pool reviewers left after the exclusions
+    pub reviewers: Vec<Pubkey>, // Max 5
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewerDraw {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // pool (Pubkey)
+        32 + // paper (Pubkey)
+        32 + // drawn_by (Pubkey)
+        8 + // slot (u64)
+        32 + // seed ([u8; 32])
+        4 + // candidates (u32)
+        (4 + Self::MAX_DRAWN * 32) + // reviewers (max 5 pubkeys + prefix)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ReviewerDraw {
+    pub const MAX_DRAWN: usize = 5;
+
+    //Picks count candidates without replacement, pick i uses sha256(seed || i)
+    pub fn pick(seed: &[u8; 32], mut candidates: Vec<Pubkey>, count: usize) -> Vec<Pubkey> {
+        let mut drawn = Vec::with_capacity(count);
+        for i in 0..count as u8 {
+            let hash = hashv(&[seed, &[i]]).to_bytes();
+            let mut value = [0u8; 8];
+            value.copy_from_slice(&hash[..8]);
+            let index = (u64::from_le_bytes(value) % (candidates.len() as u64)) as usize;
+            drawn.push(candidates.remove(index));
+        }
+        drawn
+    }
+}
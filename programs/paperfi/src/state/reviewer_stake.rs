@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+//Lamports locked by a reviewer, held by this account on top of its rent
+#[account]
+pub struct ReviewerStake {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_at: u64, // 0 while locked, set by request_unstake
+    pub slashed: u64, // Total slashed by moderators
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for ReviewerStake {
+    const INIT_SPACE: usize =
+        8 + // Anchor discriminator
+        32 + // owner (Pubkey)
+        8 + // amount (u64)
+        8 + // unlock_at (u64)
+        8 + // slashed (u64)
+        8 + // timestamp (u64)
+        1; // bump (u8)
+}
+
+impl ReviewerStake {
+    //Stakes being unlocked don't count
+    pub fn check(stake: Option<&ReviewerStake>, min_stake: u64) -> Result<()> {
+        if min_stake == 0 {
+            return Ok(());
+        }
+        require!(
+            stake.is_some_and(|stake| stake.amount >= min_stake && stake.unlock_at == 0),
+            ErrorCode::StakeRequired
+        );
+        Ok(())
+    }
+}
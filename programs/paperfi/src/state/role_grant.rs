@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::helpers::Role;
+
+#[account]
+pub struct RoleGrant {
+    pub wallet: Pubkey, // The wallet holding the roles
+    pub roles: u8, // Bitmask of Role
+    pub granted_by: Pubkey, // Last admin that changed the roles
+    pub timestamp: u64,
+    pub bump: u8,
+}
+
+impl Space for RoleGrant {
+    const INIT_SPACE: usize = 8 + 32 + 1 + 32 + 8 + 1;
+}
+
+impl RoleGrant {
+    //SuperAdmin holds every role
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles & (role.mask() | Role::SuperAdmin.mask()) != 0
+    }
+}
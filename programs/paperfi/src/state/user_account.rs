@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::helpers::{ validate_text, USER_AFFILIATION };
 
 #[account]
 pub struct UserAccount {
@@ -6,18 +9,31 @@ pub struct UserAccount {
     pub title: String,
     pub purchases: u32,
     pub papers: u32, //published
-    pub reviews: u32,
+    pub reviews: u32, // Papers reviewed, a paper counts once whatever the versions reviewed
     pub owner: Pubkey,
     pub bump: u8,
     pub vault_bump: u8,
     pub timestamp: u64,
+    pub badges: u32, // badges printed
+    pub helpful_votes: u32, // peer ratings received on reviews
+    pub unhelpful_votes: u32,
+    pub review_badge_record: u32, // Highest reviews record claimed with a badge
+    pub paper_badge_record: u32, // Highest papers record claimed with a badge
+    pub purchase_badge_record: u32, // Highest purchases record claimed with a badge
+    pub helpful_badge_record: u32, // Highest helpful votes record claimed with a badge
+    pub affiliation: String, // Institution, checked against paper conflict lists
+    pub previous_affiliation: String, // Affiliation before the last change, still checked
+    pub affiliation_updated_at: u64,
+    pub created_at: u64, // Signup time, timestamp moves with every action
+    pub missed_reviews: u32, // Invitations and commitments left past their deadline
+    pub version_reviews: u32, // Reviews of later versions of papers already counted in reviews
 }
 
 impl anchor_lang::Space for UserAccount {
     const INIT_SPACE: usize =
         8 + // Anchor discriminator
-        (48 + 4) + // name (max 48 chars + prefix)
-        (32 + 4) + // title (max 32 chars + prefix)
+        (144 + 4) + // name (max 48 chars / 144 bytes + prefix)
+        (96 + 4) + // title (max 32 chars / 96 bytes + prefix)
         4 + // purchases (u32)
         4 + // papers (u32)
         4 + // reviews (u32)
@@ -25,5 +41,88 @@ impl anchor_lang::Space for UserAccount {
         32 + // vault (Pubkey)
         1 + // bump (u8)
         1 + // vault_bump (u8)
-        8; // timestamp (u64)
+        8 + // timestamp (u64)
+        4 + // badges (u32)
+        4 + // helpful_votes (u32)
+        4 + // unhelpful_votes (u32)
+        4 + // review_badge_record (u32)
+        4 + // paper_badge_record (u32)
+        4 + // purchase_badge_record (u32)
+        4 + // helpful_badge_record (u32)
+        (192 + 4) + // affiliation (max 64 chars / 192 bytes + prefix)
+        (192 + 4) + // previous_affiliation (max 64 chars / 192 bytes + prefix)
+        8 + // affiliation_updated_at (u64)
+        8 + // created_at (u64)
+        4 + // missed_reviews (u32)
+        4; // version_reviews (u32)
+}
+
+impl UserAccount {
+    //Weight of this user's verdicts in hundredths, from reviews written, badges held and peer ratings
+    pub fn reputation_weight(&self) -> u64 {
+        let reviews = (self.reviews as u64).min(MAX_WEIGHTED_REVIEWS);
+        let badges = (self.badges as u64).min(MAX_WEIGHTED_BADGES);
+        let helpful = (self.helpful_votes as u64).min(MAX_WEIGHTED_PEER_VOTES);
+        let unhelpful = (self.unhelpful_votes as u64).min(MAX_WEIGHTED_PEER_VOTES);
+
+        let weight = BASE_REVIEW_WEIGHT +
+            reviews * WEIGHT_PER_REVIEW +
+            badges * WEIGHT_PER_BADGE +
+            helpful * WEIGHT_PER_PEER_VOTE;
+        let penalty = unhelpful * WEIGHT_PER_PEER_VOTE;
+
+        weight.saturating_sub(penalty).clamp(MIN_REVIEW_WEIGHT, MAX_REVIEW_WEIGHT)
+    }
+
+    //A paper counts once towards the reputation and the badges, re-reviews of its versions are counted apart
+    pub fn add_review(&mut self, first_on_paper: bool, now: u64) {
+        if first_on_paper {
+            self.reviews += 1;
+        } else {
+            self.version_reviews += 1;
+        }
+        self.timestamp = now;
+    }
+
+    //Affiliations are self declared, a change is rate limited and the previous one keeps matching conflict lists
+    pub fn set_affiliation(&mut self, affiliation: Option<String>, now: u64) -> Result<()> {
+        let Some(affiliation) = affiliation.filter(|affiliation| *affiliation != self.affiliation) else {
+            return Ok(());
+        };
+        validate_text(&affiliation, &USER_AFFILIATION)?;
+
+        if !self.affiliation.is_empty() {
+            require!(
+                now >= self.affiliation_updated_at.saturating_add(AFFILIATION_COOLDOWN_SECONDS),
+                ErrorCode::AffiliationLocked
+            );
+        }
+
+        self.previous_affiliation = std::mem::replace(&mut self.affiliation, affiliation);
+        self.affiliation_updated_at = now;
+        Ok(())
+    }
+
+    //Registers a badge for the next tier of an achievement, a tier is only counted once.
+    //Other records still print, they just don't add to the reputation
+    pub fn claim_badge(&mut self, name: &str, record: u32) -> Result<()> {
+        require!(record > 0, ErrorCode::InvalidAchievement);
+
+        let claimed = match name {
+            "papers" => &mut self.paper_badge_record,
+            "reviews" => &mut self.review_badge_record,
+            "purchases" => &mut self.purchase_badge_record,
+            "helpful" => &mut self.helpful_badge_record,
+            _ => {
+                return err!(ErrorCode::UnknownBadge);
+            }
+        };
+        if !BADGE_TIERS.contains(&record) || record <= *claimed {
+            return Ok(());
+        }
+        *claimed = record;
+
+        self.badges = self.badges.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ import {
   LAMPORTS_PER_SOL,
   PublicKey,
   SystemProgram,
+  SYSVAR_SLOT_HASHES_PUBKEY,
 } from '@solana/web3.js';
 import { before, it } from 'mocha';
 import {
@@ -13,7 +14,7 @@ import {
   getLogs,
   makeKeypairs,
 } from '@solana-developers/helpers';
-import { randomBytes } from 'node:crypto';
+import { randomBytes, createHash } from 'node:crypto';
 import { assert, expect } from 'chai';
 import {
   MPL_CORE_PROGRAM_ID,
@@ -49,6 +50,19 @@ describe('PaperFi', () => {
    //create a random id for the paper
    const id = new BN(randomBytes(8));
    const id2 = new BN(randomBytes(8));
+   const id3 = new BN(randomBytes(8));
+  const id4 = new BN(randomBytes(8));
+   //reviews are scoped to the paper version, papers start at version 1
+   const paperVersion = new BN(1).toArrayLike(Buffer, 'le', 4);
+
+   //Invited reviewers hide behind a commitment of their wallet and a salt
+   const invitationSalt = randomBytes(32);
+   const invitationCommitment = (reviewer: PublicKey) =>
+     createHash('sha256')
+       .update(Buffer.concat([reviewer.toBuffer(), invitationSalt]))
+       .digest();
+   //One-off key the invited reviewer seals the review with, so the wallet stays out of it until the reveal
+   const invitationReviewKey = Keypair.generate();
  
 //DEVNET WALLETS
 console.log("--------------- LOADING WALLETS -----------------")
@@ -215,6 +229,87 @@ console.log("--------------- WALLETS LOADED -----------------")
     }
   });
 
+  it('Karen attempts to add herself as an admin', async () => {
+    try {
+      const addAdminIx = await program.methods
+        .addAdmin(karen.publicKey)
+        .accountsPartial({
+          admin: karen.publicKey,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(addAdminIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to add herself as an admin');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'Unauthorized');
+    }
+  });
+
+  it('Admin adds and removes a second admin', async () => {
+    const [configAccountAdress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const addAdminIx = await program.methods
+        .addAdmin(roger.publicKey)
+        .accountsPartial({
+          admin: admin.publicKey,
+        })
+        .instruction();
+
+      const removeAdminIx = await program.methods
+        .removeAdmin(roger.publicKey)
+        .accountsPartial({
+          admin: admin.publicKey,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(addAdminIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+
+      let configAccount = await program.account.paperFiConfig.fetch(
+        configAccountAdress
+      );
+      assert.equal(configAccount.admins.length, 2);
+
+      const tx2 = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(removeAdminIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx2, [admin]);
+
+      configAccount = await program.account.paperFiConfig.fetch(
+        configAccountAdress
+      );
+      assert.equal(configAccount.admins.length, 1);
+      assert.equal(configAccount.admins[0].toString(), admin.publicKey.toString());
+    } catch (e: any) {
+      console.error('Error:', e);
+      assert.fail('Failed to add and remove an admin');
+    }
+  });
+
   //------------------- Initialize User tests --------------------
   it('Bob Signing up test', async () => {
     console.log('------- User Signing up ------------');
@@ -551,6 +646,7 @@ console.log("--------------- WALLETS LOADED -----------------")
     const editUserParams = {
       name: null,
       title: 'Little Genius',
+      affiliation: 'University of Lisbon',
     };
 
     const [userAccountWallet, userAccountBump] =
@@ -593,12 +689,53 @@ console.log("--------------- WALLETS LOADED -----------------")
     );
 
     assert.equal(userAccount.title, editUserParams.title);
+    assert.equal(userAccount.affiliation, editUserParams.affiliation);
+  });
+
+  it('Bob attempts to change the affiliation right after setting it', async () => {
+    const editUserParams = {
+      name: null,
+      title: null,
+      affiliation: 'University of Porto',
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: bob.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to change the affiliation during the cooldown');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'AffiliationLocked');
+    }
   });
 
   it('Bob Edits user_account with incorrect parameters test', async () => {
     const editUserParams = {
       name: 'Bob the name that was too long to right in this the program',
       title: '',
+      affiliation: null,
     };
 
     const userAccountWallet = await PublicKey.findProgramAddressSync(
@@ -641,13 +778,213 @@ console.log("--------------- WALLETS LOADED -----------------")
     }
   });
 
+  it('Roger Edits user_account with a Japanese name and title', async () => {
+    const editUserParams = {
+      name: 'ロジャー・オーサー',
+      title: '主任研究員',
+      affiliation: null,
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), roger.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: roger.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: roger.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [roger]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Failed to edit Roger user_account with a Japanese title');
+    }
+
+    const userAccount = await program.account.userAccount.fetch(
+      userAccountWallet
+    );
+
+    assert.equal(userAccount.name, editUserParams.name);
+    assert.equal(userAccount.title, editUserParams.title);
+  });
+
+  it('Roger Edits user_account with a bidi override character', async () => {
+    const editUserParams = {
+      name: 'Roger \u202eAuthor',
+      title: null,
+      affiliation: null,
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), roger.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: roger.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: roger.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [roger]);
+
+      assert.fail('Roger was able to use a bidi override in his name');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'BidiOverrideNotAllowed');
+    }
+  });
+
+  it('Roger Edits user_account with a right-to-left mark', async () => {
+    const editUserParams = {
+      name: 'Roger \u200fAuthor',
+      title: null,
+      affiliation: null,
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), roger.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: roger.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: roger.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [roger]);
+
+      assert.fail('Roger was able to use a right-to-left mark in the name');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'BidiOverrideNotAllowed');
+    }
+  });
+
+  it('Roger Edits user_account with an isolate control', async () => {
+    const editUserParams = {
+      name: 'Roger \u2067Author\u2069',
+      title: null,
+      affiliation: null,
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), roger.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: roger.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: roger.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [roger]);
+
+      assert.fail('Roger was able to use a bidi isolate in the name');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'BidiOverrideNotAllowed');
+    }
+  });
+
+  it('Roger Edits user_account with a soft hyphen', async () => {
+    const editUserParams = {
+      name: 'Ro\u00adger Author',
+      title: null,
+      affiliation: null,
+    };
+
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), roger.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const editUserIx = await program.methods
+        .editUser(editUserParams)
+        .accountsPartial({
+          owner: roger.publicKey,
+          user: userAccountWallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: roger.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editUserIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [roger]);
+
+      assert.fail('Roger was able to hide a soft hyphen in the name');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'ControlCharacterNotAllowed');
+    }
+  });
+
   //------------ Initialize Create Paper Tests ------------------
   it('Bob Creates Paper test', async () => {
     console.log('------- User Creating Paper --------------');
 
-    let paper_info_url = 'www.arwee.yourinfo.com/paper';
+    let paper_info_url = 'https://arweave.net/paper-info';
     let price = new BN(100000000);
-    let uri = 'www.arwee.com/paper';
+    let uri = 'ar://paper';
 
     const userAccountWallet = await PublicKey.findProgramAddressSync(
       [Buffer.from('user'), bob.publicKey.toBuffer()],
@@ -718,9 +1055,9 @@ console.log("--------------- WALLETS LOADED -----------------")
   });
 
   it('Bob Creates Paper with invalid parameters test', async () => {
-    let paper_info_url = 'www.arwee.yourinfo😍.com/paper';
+    let paper_info_url = 'https://arweave.net/paper-info😍';
     let price = new BN(100000);
-    let uri = 'www.arwee.com/paper';
+    let uri = 'ar://paper';
 
     const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
       [Buffer.from('user'), bob.publicKey.toBuffer()],
@@ -778,28 +1115,82 @@ console.log("--------------- WALLETS LOADED -----------------")
     }
   });
 
-  //------------ Initialize Edit Paper Tests ------------------
+  it('Bob Creates Paper with a URI outside the allowed gateways', async () => {
+    let paper_info_url = 'https://arweave.net/paper-info';
+    let price = new BN(100000000);
+    let uri = 'https://example.com/paper';
 
-  it('Bob Edits Paper test', async () => {
-    const editPaperParams = {
-      paperInfoUrl: null,
-      listed: null,
-      price: new BN(50000000),
-      version: null,
-      paperUri: null,
-    };
+    const [userAccountWallet, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
 
     const [paperAccountAdress, _b] = await PublicKey.findProgramAddressSync(
-      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id2.toBuffer('le', 8)],
       programId
     );
 
-    try {
-      const initilializeIx = await program.methods
-        .editPaper(id, editPaperParams)
-        .accountsPartial({
-          owner: bob.publicKey,
+    const [paperOwnerAdress, _bu] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        bob.publicKey.toBuffer(),
+        paperAccountAdress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const newPaperIx = await program.methods
+        .newPaper(id2, paper_info_url, price, uri)
+        .accountsPartial({
+          owner: bob.publicKey,
+          userAccount: userAccountWallet,
+          paper: paperAccountAdress,
+          paperAuthor: paperOwnerAdress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(newPaperIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to publish a paper hosted on an unapproved host');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'UriHostNotAllowed');
+    }
+  });
+
+  //------------ Initialize Edit Paper Tests ------------------
+
+  it('Bob Edits Paper test', async () => {
+    const editPaperParams = {
+      paperInfoUrl: null,
+      listed: null,
+      price: new BN(50000000),
+      version: null,
+      paperUri: null,
+    };
+
+    const [paperAccountAdress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const initilializeIx = await program.methods
+        .editPaper(id, editPaperParams)
+        .accountsPartial({
+          owner: bob.publicKey,
           paper: paperAccountAdress,
+          paperVersion: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -850,6 +1241,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         .accountsPartial({
           owner: bob.publicKey,
           paper: paperAccountAdress,
+          paperVersion: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -979,7 +1371,7 @@ console.log("--------------- WALLETS LOADED -----------------")
   //------------ Initialize Review Paper Tests ------------------
   it('Karen Reviews Paper with invalid parameters test', async () => {
     const verdict = { approved: {} }; // This is an example of using the `Verdict.Approved`
-    const uri = 'http://example.com/review';
+    const uri = 'https://arweave.net/review';
 
     const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
       [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
@@ -1001,6 +1393,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         karen.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1029,7 +1422,7 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     try {
       const reviewIx = await program.methods
-        .reviewPaper(id, verdict, uri)
+        .reviewPaper(id, verdict, uri, null, null)
         .accountsPartial({
           signer: karen.publicKey,
           reviewerUserAccount: reviewerAccountAddress,
@@ -1038,6 +1431,14 @@ console.log("--------------- WALLETS LOADED -----------------")
           authorPda: authorPdaAddress, // You can leave the author pda undefined if needed
           paperOwned: purchasePdaAddress, // Pass the purchase PDA (Karen hasn't purchased, so it should fail)
           review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1124,6 +1525,13 @@ console.log("--------------- WALLETS LOADED -----------------")
         programId
       );
 
+    //balances before the purchase, the fee is charged on top of the price
+    const paperBefore = await program.account.paper.fetch(paperAccountAddress);
+    const ownerVaultBefore = await connection.getBalance(userAccountAddress);
+    const configVaultBefore = await connection.getBalance(
+      configVaultAccountAddress
+    );
+
     try {
       const buyIx = await program.methods
         .buyPaper(id)
@@ -1167,11 +1575,32 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     assert.equal(paperOwned.paper.toString(), paperAccountAddress.toString());
     assert.equal(paperOwned.buyer.toString(), bond.publicKey.toString());
+
+    //the owner receives the price and PaperFi a 2% fee of it
+    const ownerVaultAfter = await connection.getBalance(userAccountAddress);
+    const configVaultAfter = await connection.getBalance(
+      configVaultAccountAddress
+    );
+    assert.equal(
+      (ownerVaultAfter - ownerVaultBefore).toString(),
+      paperBefore.price.toString()
+    );
+    assert.equal(
+      (configVaultAfter - configVaultBefore).toString(),
+      paperBefore.price.muln(2).divn(100).toString()
+    );
   });
 
   it('Bond Reviews Paper as approved', async () => {
     const verdict = { approved: {} }; // This is an example of using the `Verdict.Approved`
-    const uri = 'http://example.com/review';
+    const uri = 'https://arweave.net/review';
+    const scores = {
+      novelty: 4,
+      methodology: 5,
+      reproducibility: 3,
+      clarity: 4,
+      significance: 5,
+    };
 
     const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
       [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
@@ -1193,6 +1622,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         bond.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1219,7 +1649,7 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     try {
       const reviewIx = await program.methods
-        .reviewPaper(id, verdict, uri)
+        .reviewPaper(id, verdict, uri, scores, null)
         .accountsPartial({
           signer: bond.publicKey,
           reviewerUserAccount: reviewerAccountAddress,
@@ -1228,6 +1658,14 @@ console.log("--------------- WALLETS LOADED -----------------")
           paperOwned: purchasePdaAddress,
           paper: paperAccountAddress,
           review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1254,6 +1692,12 @@ console.log("--------------- WALLETS LOADED -----------------")
     );
 
     assert.equal(reviewAccount.owner.toString(), bond.publicKey.toString());
+    assert.equal(reviewAccount.scores.methodology, scores.methodology);
+
+    //the paper aggregates the scores, averages are x100
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+    assert.equal(paperAccount.scoreSummary.count, 1);
+    assert.equal(paperAccount.scoreSummary.averages[0], scores.novelty * 100);
   });
   it('Karen buys the Paper', async () => {
     //buyer user account
@@ -1361,7 +1805,7 @@ console.log("--------------- WALLETS LOADED -----------------")
   });
   it('Karen Reviews Paper as request for review', async () => {
     const verdict = { reviewRequested: {} }; // This is an example of using the `Verdict.ReviewRequested`
-    const uri = 'http://example.com/review';
+    const uri = 'https://arweave.net/review';
 
     const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
       [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
@@ -1383,6 +1827,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         karen.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1409,7 +1854,7 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     try {
       const reviewIx = await program.methods
-        .reviewPaper(id, verdict, uri)
+        .reviewPaper(id, verdict, uri, null, null)
         .accountsPartial({
           signer: karen.publicKey,
           reviewerUserAccount: reviewerAccountAddress,
@@ -1418,6 +1863,14 @@ console.log("--------------- WALLETS LOADED -----------------")
           paperOwned: purchasePdaAddress,
           paper: paperAccountAddress,
           review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1460,6 +1913,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         karen.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1496,7 +1950,119 @@ console.log("--------------- WALLETS LOADED -----------------")
       reviewAccountAddress
     );
 
+    //get the paper account
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
     assert.deepEqual(reviewAccount.verdict, verdict);
+    assert.equal(reviewAccount.edits, 1);
+    assert.deepEqual(reviewAccount.history[0].verdict, { reviewRequested: {} });
+    assert.equal(paperAccount.reviewStatus.reviewRequested.toNumber(), 0);
+    assert.equal(paperAccount.reviewStatus.approved.toNumber(), 2);
+  });
+
+  it('Karen flips her Review to rejected and back to approved', async () => {
+    //paper
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    //reviews of Karen and Bond
+    const [reviewAccountAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+    const [bondReviewAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const editReview = async (verdict: any) => {
+      const reviewIx = await program.methods
+        .editReview(id, verdict)
+        .accountsPartial({
+          signer: karen.publicKey,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    };
+
+    const bondReview = await program.account.review.fetch(bondReviewAddress);
+
+    //Approved -> Rejected moves the review and its weight between buckets
+    try {
+      await editReview({ rejected: {} });
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Karen failed to change her verdict to rejected');
+    }
+
+    let reviewAccount = await program.account.review.fetch(reviewAccountAddress);
+    let paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(reviewAccount.edits, 2);
+    assert.deepEqual(reviewAccount.history[1].verdict, { approved: {} });
+    assert.equal(paperAccount.reviewStatus.approved.toNumber(), 1);
+    assert.equal(paperAccount.reviewStatus.rejected.toNumber(), 1);
+    assert.equal(
+      paperAccount.reviewStatus.weightedApproved.toString(),
+      bondReview.weight.toString()
+    );
+    assert.equal(
+      paperAccount.reviewStatus.weightedRejected.toString(),
+      reviewAccount.weight.toString()
+    );
+    //two reviews are below the quorum, the paper stays listed
+    assert.equal(paperAccount.listed, true);
+
+    //Rejected -> Approved restores the previous totals
+    try {
+      await editReview({ approved: {} });
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Karen failed to change her verdict back to approved');
+    }
+
+    reviewAccount = await program.account.review.fetch(reviewAccountAddress);
+    paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(reviewAccount.edits, 3);
+    assert.equal(reviewAccount.history.length, 3);
+    assert.deepEqual(reviewAccount.history[2].verdict, { rejected: {} });
+    assert.equal(paperAccount.reviewStatus.approved.toNumber(), 2);
+    assert.equal(paperAccount.reviewStatus.rejected.toNumber(), 0);
+    assert.equal(paperAccount.reviewStatus.weightedRejected.toNumber(), 0);
+
+    //Repeating the current verdict is not an edit
+    try {
+      await editReview({ approved: {} });
+      assert.fail('Karen was able to repeat her verdict');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'VerdictUnchanged');
+    }
   });
 
   it('Roger buys the paper he owns', async () => {
@@ -1605,7 +2171,7 @@ console.log("--------------- WALLETS LOADED -----------------")
   });
   it('Roger attempts to review a paper that he owns', async () => {
     const verdict = { approved: {} }; // This is an example of using the `Verdict.Approved`
-    const uri = 'http://example.com/review';
+    const uri = 'https://arweave.net/review';
 
     const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
       [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
@@ -1627,6 +2193,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         roger.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1653,7 +2220,7 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     try {
       const reviewIx = await program.methods
-        .reviewPaper(id, verdict, uri)
+        .reviewPaper(id, verdict, uri, null, null)
         .accountsPartial({
           signer: roger.publicKey,
           reviewerUserAccount: reviewerAccountAddress,
@@ -1662,6 +2229,14 @@ console.log("--------------- WALLETS LOADED -----------------")
           paperOwned: purchasePdaAddress,
           paper: paperAccountAddress,
           review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1789,9 +2364,16 @@ console.log("--------------- WALLETS LOADED -----------------")
     assert.equal(paperOwned.paper.toString(), paperAccountAddress.toString());
   });
 
-  it('Nancy Reviews paper as rejected', async () => {
+  it('Nancy Reviews paper with a score out of range', async () => {
     const verdict = { rejected: {} }; // This is an example of using the `Verdict.rejected`
-    const uri = 'http://example.com/review';
+    const uri = 'https://arweave.net/review';
+    const scores = {
+      novelty: 2,
+      methodology: 6,
+      reproducibility: 1,
+      clarity: 3,
+      significance: 2,
+    };
 
     const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
       [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
@@ -1813,6 +2395,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         Buffer.from('review'),
         nancy.publicKey.toBuffer(),
         paperAccountAddress.toBuffer(),
+        paperVersion,
       ],
       programId
     );
@@ -1839,7 +2422,7 @@ console.log("--------------- WALLETS LOADED -----------------")
 
     try {
       const reviewIx = await program.methods
-        .reviewPaper(id, verdict, uri)
+        .reviewPaper(id, verdict, uri, scores, null)
         .accountsPartial({
           signer: nancy.publicKey,
           reviewerUserAccount: reviewerAccountAddress,
@@ -1848,6 +2431,14 @@ console.log("--------------- WALLETS LOADED -----------------")
           paperOwned: purchasePdaAddress,
           paper: paperAccountAddress,
           review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1860,46 +2451,90 @@ console.log("--------------- WALLETS LOADED -----------------")
         lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
       }).add(reviewIx);
 
-      // Send the transaction, this should fail
       await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
-    } catch (e) {
-      console.log(e.message);
-      console.log(e.logs);
-      assert.fail('Nancy failed to review the paper');
-    }
-    //get the review account
-    const reviewAccount = await program.account.review.fetch(
-      reviewAccountAddress
-    );
-
-    //get the paper account
-    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
 
-    assert.equal(reviewAccount.owner.toString(), nancy.publicKey.toString());
-    assert.equal(paperAccount.listed, false);
+      assert.fail('Nancy was able to score the paper above 5');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvalidScore');
+    }
   });
 
-  //------------ Initialize NFT Badges Test ------------------
-  it('Admin Creates NFT Badge', async () => {
-    const createBadgeParams = {
-      name: 'Publisher',
-      uri: 'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8',
+  it('Nancy Reviews paper as rejected', async () => {
+    const verdict = { rejected: {} }; // This is an example of using the `Verdict.rejected`
+    const uri = 'https://arweave.net/review';
+    const scores = {
+      novelty: 2,
+      methodology: 2,
+      reproducibility: 1,
+      clarity: 3,
+      significance: 2,
     };
 
-    //Config account
-    const [configAccountAdress, _] = await PublicKey.findProgramAddressSync(
-      [Buffer.from('paperfi_config')],
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewerAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), nancy.publicKey.toBuffer()],
+      programId
+    );
+
+    const [userAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+
+    const [reviewAccountAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        nancy.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
       programId
     );
 
+    const [purchasePdaAddress, _purchaseBump] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('purchase'),
+          nancy.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    const [authorPdaAddress, _authorBump] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('author'),
+          nancy.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
     try {
       const reviewIx = await program.methods
-        .makeBadge(createBadgeParams)
+        .reviewPaper(id, verdict, uri, scores, null)
         .accountsPartial({
-          admin: admin.publicKey,
-          badge: badgeCollection.publicKey,
-          config: configAccountAdress,
-          mplCoreProgram: mplCoreProgramId,
+          signer: nancy.publicKey,
+          reviewerUserAccount: reviewerAccountAddress,
+          userAccount: userAccountAddress,
+          authorPda: authorPdaAddress,
+          paperOwned: purchasePdaAddress,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -1907,44 +2542,187 @@ console.log("--------------- WALLETS LOADED -----------------")
       const blockhashContext = await connection.getLatestBlockhash();
 
       const tx = new anchor.web3.Transaction({
-        feePayer: admin.publicKey,
+        feePayer: nancy.publicKey,
         blockhash: blockhashContext.blockhash,
         lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
       }).add(reviewIx);
 
       // Send the transaction, this should fail
-      const sig = await anchor.web3.sendAndConfirmTransaction(
-        connection,
-        tx,
-        [admin, badgeCollection],
-        {
-          skipPreflight: true,
-          commitment: 'finalized',
-        }
-      );
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
     } catch (e) {
       console.log(e.message);
       console.log(e.logs);
-      assert.fail('Admin failed to create the NFT Collection Badge');
+      assert.fail('Nancy failed to review the paper');
     }
-
-    //Solana SDK and metaplex uses different Publickeys types
-    const collectionAsset = await fetchCollection(
-      umi,
-      badgeCollection.publicKey.toBase58()
+    //get the review account
+    const reviewAccount = await program.account.review.fetch(
+      reviewAccountAddress
     );
 
-    // Perform assertions to verify the asset's properties
-    expect(collectionAsset).to.exist;
-    assert.equal(collectionAsset.name, 'Publisher');
+    //get the paper account
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(reviewAccount.owner.toString(), nancy.publicKey.toString());
     assert.equal(
-      collectionAsset.uri,
-      'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8'
+      paperAccount.reviewStatus.weightedRejected.toString(),
+      reviewAccount.weight.toString()
+    );
+    assert.equal(paperAccount.listed, false);
+
+    //scores of Bond and Nancy are aggregated, averages are x100
+    assert.equal(paperAccount.scoreSummary.count, 2);
+    assert.deepEqual(paperAccount.scoreSummary.totals, [6, 7, 4, 7, 7]);
+    assert.deepEqual(
+      paperAccount.scoreSummary.averages,
+      [300, 350, 200, 350, 350]
     );
   });
-  it('User attempts to Creates NFT Badge (not admin)', async () => {
-    const createBadgeParams = {
-      name: 'Publisher',
+
+  it('Paper weighted totals add up the reviewer weights', async () => {
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const reviewOf = async (reviewer: PublicKey) => {
+      const [reviewAddress, _] = await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('review'),
+          reviewer.toBuffer(),
+          paperAccountAddress.toBuffer(),
+          paperVersion,
+        ],
+        programId
+      );
+      return program.account.review.fetch(reviewAddress);
+    };
+
+    const bondReview = await reviewOf(bond.publicKey);
+    const karenReview = await reviewOf(karen.publicKey);
+    const nancyReview = await reviewOf(nancy.publicKey);
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    //first reviews without badges or ratings weigh the base 1.00
+    assert.equal(bondReview.weight.toNumber(), 100);
+    assert.equal(karenReview.weight.toNumber(), 100);
+    assert.equal(nancyReview.weight.toNumber(), 100);
+    assert.equal(
+      paperAccount.reviewStatus.weightedApproved.toString(),
+      bondReview.weight.add(karenReview.weight).toString()
+    );
+    assert.equal(
+      paperAccount.reviewStatus.weightedRejected.toString(),
+      nancyReview.weight.toString()
+    );
+    assert.equal(paperAccount.reviewStatus.weightedReviewRequested.toNumber(), 0);
+  });
+
+  //------------ Paper Sales Analytics Test ------------------
+  it('Bob checks the Paper sales stats', async () => {
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    const stats = await program.methods
+      .paperSales(id)
+      .accountsPartial({
+        paper: paperAccountAddress,
+      })
+      .view();
+
+    //Bond, Karen and Nancy paid for the paper, Roger is an author and got it for free
+    const paidSales = 3;
+    const solRevenue = stats.revenue.find(
+      entry => entry.currency.toString() === SystemProgram.programId.toString()
+    );
+
+    assert.equal(paperAccount.sales, 4);
+    assert.equal(
+      solRevenue.revenue.toString(),
+      paperAccount.price.muln(paidSales).toString()
+    );
+    assert.equal(
+      solRevenue.fees.toString(),
+      paperAccount.price.muln(2).divn(100).muln(paidSales).toString()
+    );
+    assert.isTrue(stats.firstSale.gt(new BN(0)));
+    assert.isTrue(stats.lastSale.gte(stats.firstSale));
+    assert.equal(
+      stats.periodSales.reduce((total, sales) => total + sales, 0),
+      paperAccount.sales
+    );
+  });
+
+  //------------ Initialize NFT Badges Test ------------------
+  it('Admin Creates NFT Badge', async () => {
+    const createBadgeParams = {
+      name: 'Publisher',
+      uri: 'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8',
+    };
+
+    //Config account
+    const [configAccountAdress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const reviewIx = await program.methods
+        .makeBadge(createBadgeParams)
+        .accountsPartial({
+          admin: admin.publicKey,
+          roleGrant: null,
+          badge: badgeCollection.publicKey,
+          config: configAccountAdress,
+          mplCoreProgram: mplCoreProgramId,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      // Send the transaction, this should fail
+      const sig = await anchor.web3.sendAndConfirmTransaction(
+        connection,
+        tx,
+        [admin, badgeCollection],
+        {
+          skipPreflight: true,
+          commitment: 'finalized',
+        }
+      );
+    } catch (e) {
+      console.log(e.message);
+      console.log(e.logs);
+      assert.fail('Admin failed to create the NFT Collection Badge');
+    }
+
+    //Solana SDK and metaplex uses different Publickeys types
+    const collectionAsset = await fetchCollection(
+      umi,
+      badgeCollection.publicKey.toBase58()
+    );
+
+    // Perform assertions to verify the asset's properties
+    expect(collectionAsset).to.exist;
+    assert.equal(collectionAsset.name, 'Publisher');
+    assert.equal(
+      collectionAsset.uri,
+      'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8'
+    );
+  });
+  it('User attempts to Creates NFT Badge (not admin)', async () => {
+    const createBadgeParams = {
+      name: 'Publisher',
       uri: 'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8',
     };
 
@@ -1959,6 +2737,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         .makeBadge(createBadgeParams)
         .accountsPartial({
           admin: bob.publicKey,
+          roleGrant: null,
           badge: badgeCollection2.publicKey,
           config: configAccountAdress,
           mplCoreProgram: mplCoreProgramId,
@@ -2050,6 +2829,12 @@ console.log("--------------- WALLETS LOADED -----------------")
     const asset = await fetchAsset(umi, badgeNFT.publicKey.toBase58());
     expect(asset).to.exist;
     assert.equal(asset.name, 'papers');
+
+    const userAccount = await program.account.userAccount.fetch(
+      userAccountAddress
+    );
+    assert.equal(userAccount.badges, 1);
+    assert.equal(userAccount.paperBadgeRecord, 1);
   });
   it('Bob Mints NFT Badge with invalid parameters', async () => {
     const printBadgeArgs = {
@@ -2114,6 +2899,118 @@ console.log("--------------- WALLETS LOADED -----------------")
   });
 
   //------------ Initialize Withdraw Funds Test ------------------
+  it('Bob prints a second badge for the same tier without counting it twice', async () => {
+    const printBadgeArgs = {
+      name: 'papers',
+      uri: 'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8',
+      achievement: 'First Timer',
+      record: 1,
+    };
+    const asset = Keypair.generate();
+
+    const [userAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+
+    //Config account
+    const [configAccountAdress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const printIx = await program.methods
+        .mintAchievementNft(printBadgeArgs)
+        .accountsPartial({
+          user: bob.publicKey,
+          userAccount: userAccountAddress,
+          config: configAccountAdress,
+          collection: badgeCollection.publicKey,
+          asset: asset.publicKey,
+          mplCoreProgram: mplCoreProgramId,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(printIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob, asset]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bob failed to print a second badge');
+    }
+
+    //the reputation still counts a single badge
+    const userAccount = await program.account.userAccount.fetch(
+      userAccountAddress
+    );
+    assert.equal(userAccount.badges, 1);
+  });
+
+  it('Bob attempts to print a badge for a zero record', async () => {
+    const printBadgeArgs = {
+      name: 'papers',
+      uri: 'https://arweave.net/Q_njzBo9OP491p8WVqwx-um0Q4Bbk1MO2BsnnQ2ClY8',
+      achievement: 'First Timer',
+      record: 0,
+    };
+    const asset = Keypair.generate();
+
+    const [userAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+
+    //Config account
+    const [configAccountAdress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const printIx = await program.methods
+        .mintAchievementNft(printBadgeArgs)
+        .accountsPartial({
+          user: bob.publicKey,
+          userAccount: userAccountAddress,
+          config: configAccountAdress,
+          collection: badgeCollection.publicKey,
+          asset: asset.publicKey,
+          mplCoreProgram: mplCoreProgramId,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(printIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob, asset]);
+
+      assert.fail('Bob printed a badge for a zero record');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvalidAchievement');
+    }
+
+    //the reputation still counts a single badge
+    const userAccount = await program.account.userAccount.fetch(
+      userAccountAddress
+    );
+    assert.equal(userAccount.badges, 1);
+  });
+
   it('Bob Withdraws Funds', async () => {
     const [userVaultAddress, userVaultBump] =
       await PublicKey.findProgramAddressSync(
@@ -2245,6 +3142,7 @@ console.log("--------------- WALLETS LOADED -----------------")
       .adminWithdraw()
       .accountsPartial({
         admin: admin.publicKey,
+        roleGrant: null,
         config: configAccountAddress,
         configVault: configVaultAccountAddress,
         systemProgram: SystemProgram.programId,
@@ -2267,6 +3165,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         .adminWithdraw()
         .accountsPartial({
           admin: admin.publicKey,
+          roleGrant: null,
           config: configAccountAddress,
           configVault: configVaultAccountAddress,
           systemProgram: SystemProgram.programId,
@@ -2318,6 +3217,7 @@ console.log("--------------- WALLETS LOADED -----------------")
         .adminWithdraw()
         .accountsPartial({
           admin: admin.publicKey,
+          roleGrant: null,
           config: configAccountAddress,
           configVault: configVaultAccountAddress,
           systemProgram: SystemProgram.programId,
@@ -2343,4 +3243,3886 @@ console.log("--------------- WALLETS LOADED -----------------")
       );
     }
   });
+
+  //------------ Roles Tests ------------------
+  it('Admin grants the FeeManager role to Bond', async () => {
+    const [roleGrantAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), bond.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const grantIx = await program.methods
+        .grantRole(bond.publicKey, { feeManager: {} })
+        .accountsPartial({
+          admin: admin.publicKey,
+          adminRole: null,
+          roleGrant: roleGrantAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(grantIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to grant the FeeManager role');
+    }
+
+    const roleGrant = await program.account.roleGrant.fetch(roleGrantAddress);
+
+    assert.equal(roleGrant.wallet.toString(), bond.publicKey.toString());
+    assert.equal(roleGrant.roles & (1 << 1), 1 << 1);
+  });
+
+  it('Karen attempts to set the platform fee without a role', async () => {
+    try {
+      const setFeeIx = await program.methods
+        .setFee(10)
+        .accountsPartial({
+          admin: karen.publicKey,
+          roleGrant: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(setFeeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to change the platform fee');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'Unauthorized');
+    }
+  });
+
+  it('Admin revokes the FeeManager role from Bond', async () => {
+    const [roleGrantAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), bond.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const revokeIx = await program.methods
+        .revokeRole({ feeManager: {} })
+        .accountsPartial({
+          admin: admin.publicKey,
+          adminRole: null,
+          roleGrant: roleGrantAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(revokeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to revoke the FeeManager role');
+    }
+
+    const roleGrant = await program.account.roleGrant.fetch(roleGrantAddress);
+
+    assert.equal(roleGrant.roles, 0);
+  });
+
+  //------------ Moderation Tests ------------------
+  it('Karen reports the Paper for plagiarism', async () => {
+    const evidenceUri = 'https://arweave.net/plagiarism-evidence';
+
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reportAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('report'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const reportIx = await program.methods
+        .reportPaper(id, { plagiarism: {} }, evidenceUri)
+        .accountsPartial({
+          reporter: karen.publicKey,
+          paper: paperAccountAddress,
+          report: reportAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reportIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Karen failed to report the paper');
+    }
+
+    const reportAccount = await program.account.report.fetch(
+      reportAccountAddress
+    );
+
+    assert.equal(reportAccount.paper.toString(), paperAccountAddress.toString());
+    assert.deepEqual(reportAccount.status, { open: {} });
+  });
+
+  it('Admin takes the Paper down', async () => {
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reportAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('report'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const takeDownIx = await program.methods
+        .takeDownPaper(id)
+        .accountsPartial({
+          moderator: admin.publicKey,
+          roleGrant: null,
+          paper: paperAccountAddress,
+          report: reportAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(takeDownIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to take the paper down');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(paperAccount.listed, false);
+    assert.deepEqual(paperAccount.moderation.status, { takenDown: {} });
+    assert.equal(
+      paperAccount.moderation.report.toString(),
+      reportAccountAddress.toString()
+    );
+  });
+
+  it('Bob appeals the takedown', async () => {
+    const appealUri = 'https://arweave.net/takedown-appeal';
+
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const appealIx = await program.methods
+        .appealTakedown(id, appealUri)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(appealIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to appeal the takedown');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.deepEqual(paperAccount.moderation.status, { appealed: {} });
+    assert.equal(paperAccount.moderation.appealUri, appealUri);
+  });
+
+  it('Admin overturns the takedown', async () => {
+    const [paperAccountAddress, _bump] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reportAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('report'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const resolveIx = await program.methods
+        .resolveTakedownAppeal(id, true)
+        .accountsPartial({
+          moderator: admin.publicKey,
+          roleGrant: null,
+          paper: paperAccountAddress,
+          report: reportAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(resolveIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to resolve the appeal');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+    const reportAccount = await program.account.report.fetch(
+      reportAccountAddress
+    );
+
+    //the paper gets back the listing it had before the takedown
+    assert.equal(paperAccount.listed, paperAccount.moderation.listedBefore);
+    assert.deepEqual(paperAccount.moderation.status, { active: {} });
+    assert.deepEqual(reportAccount.status, { overturned: {} });
+  });
+
+  //------------ Review Policy Tests ------------------
+  it('Admin sets the review policy', async () => {
+    const reviewPolicy = {
+      minQuorum: 3,
+      rejectionThresholdBps: 2000,
+      countReviewRequested: true,
+      autoDelist: true,
+    };
+
+    const [configAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const policyIx = await program.methods
+        .setReviewPolicy(reviewPolicy)
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: configAccountAddress,
+          roleGrant: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(policyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to set the review policy');
+    }
+
+    const configAccount = await program.account.paperFiConfig.fetch(
+      configAccountAddress
+    );
+
+    assert.equal(configAccount.reviewPolicy.minQuorum, reviewPolicy.minQuorum);
+    assert.equal(
+      configAccount.reviewPolicy.rejectionThresholdBps,
+      reviewPolicy.rejectionThresholdBps
+    );
+  });
+
+  it('Admin sets a review policy with an invalid threshold', async () => {
+    const reviewPolicy = {
+      minQuorum: 3,
+      rejectionThresholdBps: 20000,
+      countReviewRequested: true,
+      autoDelist: true,
+    };
+
+    try {
+      const policyIx = await program.methods
+        .setReviewPolicy(reviewPolicy)
+        .accountsPartial({
+          admin: admin.publicKey,
+          roleGrant: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(policyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+
+      assert.fail('Admin was able to set a threshold above 100%');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvalidReviewPolicy');
+    }
+  });
+
+  it('Bob opens a commit-reveal review round', async () => {
+    const commitDuration = new BN(60 * 60);
+    const revealDuration = new BN(60 * 60);
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const roundIx = await program.methods
+        .openReviewRound(id, commitDuration, revealDuration)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(roundIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to open a review round');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(
+      paperAccount.reviewRound.revealDeadline.toNumber(),
+      paperAccount.reviewRound.commitDeadline.toNumber() +
+        revealDuration.toNumber()
+    );
+    assert.equal(paperAccount.reviewRound.commitments, 0);
+  });
+
+  it('Bob attempts to open a second review round while one is open', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const roundIx = await program.methods
+        .openReviewRound(id, new BN(60), new BN(60))
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(roundIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to open two review rounds');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'ReviewRoundActive');
+    }
+  });
+
+  it('Bob responds to the review from Nancy', async () => {
+    const uri = 'https://arweave.net/response';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        nancy.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const [responseAccountAddress, _bu] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('response'), reviewAccountAddress.toBuffer()],
+        programId
+      );
+
+    try {
+      const responseIx = await program.methods
+        .respondToReview(id, uri, null)
+        .accountsPartial({
+          signer: bob.publicKey,
+          paper: paperAccountAddress,
+          paperAuthor: null,
+          review: reviewAccountAddress,
+          response: responseAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(responseIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to respond to the review');
+    }
+
+    const responseAccount = await program.account.reviewResponse.fetch(
+      responseAccountAddress
+    );
+
+    assert.equal(responseAccount.responseUri, uri);
+    assert.equal(responseAccount.responder.toString(), bob.publicKey.toString());
+    assert.isFalse(responseAccount.acknowledged);
+  });
+
+  it('Nancy acknowledges the response and requests a new review', async () => {
+    const verdict = { reviewRequested: {} };
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        nancy.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const [responseAccountAddress, _bu] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('response'), reviewAccountAddress.toBuffer()],
+        programId
+      );
+
+    try {
+      const acknowledgeIx = await program.methods
+        .acknowledgeResponse(id, verdict)
+        .accountsPartial({
+          signer: nancy.publicKey,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          response: responseAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(acknowledgeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Nancy failed to acknowledge the response');
+    }
+
+    const responseAccount = await program.account.reviewResponse.fetch(
+      responseAccountAddress
+    );
+    const reviewAccount = await program.account.review.fetch(
+      reviewAccountAddress
+    );
+
+    assert.isTrue(responseAccount.acknowledged);
+    assert.deepEqual(reviewAccount.verdict, verdict);
+    assert.equal(reviewAccount.history.length, 1);
+  });
+
+  it('Karen withdraws her review', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewerAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), karen.publicKey.toBuffer()],
+      programId
+    );
+
+    const [reviewAccountAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const paperBefore = await program.account.paper.fetch(paperAccountAddress);
+    const reviewerBefore = await program.account.userAccount.fetch(
+      reviewerAccountAddress
+    );
+
+    try {
+      const withdrawIx = await program.methods
+        .withdrawReview(id)
+        .accountsPartial({
+          signer: karen.publicKey,
+          reviewerUserAccount: reviewerAccountAddress,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          responder: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(withdrawIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Karen failed to withdraw her review');
+    }
+
+    const paperAfter = await program.account.paper.fetch(paperAccountAddress);
+    const reviewerAfter = await program.account.userAccount.fetch(
+      reviewerAccountAddress
+    );
+    const reviewAccount = await connection.getAccountInfo(reviewAccountAddress);
+
+    //Karen's review was edited to approved
+    assert.isNull(reviewAccount);
+    assert.equal(paperAfter.reviews, paperBefore.reviews - 1);
+    assert.equal(
+      paperAfter.reviewStatus.approved.toNumber(),
+      paperBefore.reviewStatus.approved.toNumber() - 1
+    );
+    assert.equal(reviewerAfter.reviews, reviewerBefore.reviews - 1);
+  });
+
+  it('Bob attempts to publish a new version during the review round', async () => {
+    const editPaperParams = {
+      paperInfoUrl: null,
+      listed: null,
+      price: null,
+      version: 2,
+      paperUri: 'ar://paper-v2',
+    };
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    //archive of the version being replaced
+    const [versionAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper_version'), paperAccountAddress.toBuffer(), paperVersion],
+      programId
+    );
+
+    try {
+      const editIx = await program.methods
+        .editPaper(id, editPaperParams)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          paperVersion: versionAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to publish a new version during a review round');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'ReviewRoundActive');
+    }
+  });
+
+  it('Bob excludes Karen and his own institution from reviewing the paper', async () => {
+    const wallets = [karen.publicKey];
+    const affiliations = ['University of Lisbon'];
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [conflictsAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('conflicts'), paperAccountAddress.toBuffer()],
+        programId
+      );
+
+    try {
+      const conflictsIx = await program.methods
+        .setConflicts(id, wallets, affiliations)
+        .accountsPartial({
+          signer: bob.publicKey,
+          paper: paperAccountAddress,
+          paperAuthor: null,
+          conflicts: conflictsAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(conflictsIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to set the conflict list');
+    }
+
+    const conflictsAccount = await program.account.conflictList.fetch(
+      conflictsAccountAddress
+    );
+
+    assert.equal(
+      conflictsAccount.excludedWallets[0].toString(),
+      karen.publicKey.toString()
+    );
+    assert.deepEqual(conflictsAccount.excludedAffiliations, affiliations);
+  });
+
+  it('Karen attempts to review the paper again while excluded', async () => {
+    const verdict = { rejected: {} };
+    const uri = 'https://arweave.net/review';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewerAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), karen.publicKey.toBuffer()],
+      programId
+    );
+
+    const [userAccountAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const reviewIx = await program.methods
+        .reviewPaper(id, verdict, uri, null, null)
+        .accountsPartial({
+          signer: karen.publicKey,
+          reviewerUserAccount: reviewerAccountAddress,
+          userAccount: userAccountAddress,
+          paper: paperAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to review a paper she is excluded from');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'ConflictOfInterest');
+    }
+  });
+
+  it('Bob creates an editorial board and adds Nancy', async () => {
+    const name = 'PaperFi Reviewers';
+
+    const [boardAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('board'), bob.publicKey.toBuffer(), Buffer.from(name)],
+      programId
+    );
+
+    const [memberAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board_member'),
+        boardAccountAddress.toBuffer(),
+        nancy.publicKey.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const boardIx = await program.methods
+        .createBoard(name)
+        .accountsPartial({
+          authority: bob.publicKey,
+          board: boardAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const memberIx = await program.methods
+        .addBoardMember(nancy.publicKey)
+        .accountsPartial({
+          authority: bob.publicKey,
+          board: boardAccountAddress,
+          boardMember: memberAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(boardIx, memberIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to create the editorial board');
+    }
+
+    const boardAccount = await program.account.editorialBoard.fetch(
+      boardAccountAddress
+    );
+    const memberAccount = await program.account.boardMember.fetch(
+      memberAccountAddress
+    );
+
+    assert.equal(boardAccount.members, 1);
+    assert.equal(memberAccount.member.toString(), nancy.publicKey.toString());
+  });
+
+  it('Bob attempts to require the board once the Paper has reviews', async () => {
+    const [boardAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const requirements = {
+      minAccountAge: new BN(0),
+      minPapers: 0,
+      badgeCollection: null,
+      editorialBoard: boardAccountAddress,
+    };
+
+    const [paperAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const requirementsIx = await program.methods
+        .setPaperRequirements(id, requirements)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(requirementsIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob changed the requirements after the reviews arrived');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'RequirementsLocked');
+    }
+  });
+
+  it('Bob funds a SOL review bounty', async () => {
+    const payout = new BN(0.01 * LAMPORTS_PER_SOL);
+    const maxReviews = 2;
+    const duration = new BN(60 * 60);
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const [bountyVaultAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty_vault'), bountyAccountAddress.toBuffer()],
+      programId
+    );
+
+    try {
+      const bountyIx = await program.methods
+        .fundBounty(id, payout, maxReviews, duration)
+        .accountsPartial({
+          funder: bob.publicKey,
+          paper: paperAccountAddress,
+          paperAuthor: null,
+          bounty: bountyAccountAddress,
+          bountyVault: bountyVaultAddress,
+          mint: null,
+          funderTokenAccount: null,
+          bountyTokenVault: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(bountyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to fund the review bounty');
+    }
+
+    const bountyAccount = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+    const vaultBalance = await connection.getBalance(bountyVaultAddress);
+
+    assert.isNull(bountyAccount.mint);
+    assert.equal(bountyAccount.paidReviews, 0);
+    assert.equal(vaultBalance, payout.toNumber() * maxReviews);
+  });
+
+  it('Bob attempts to refund the bounty before it expires', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const [bountyVaultAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty_vault'), bountyAccountAddress.toBuffer()],
+      programId
+    );
+
+    try {
+      const refundIx = await program.methods
+        .refundBounty(id)
+        .accountsPartial({
+          funder: bob.publicKey,
+          paper: paperAccountAddress,
+          bounty: bountyAccountAddress,
+          bountyVault: bountyVaultAddress,
+          bountyTokenVault: null,
+          funderTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(refundIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to refund an open bounty');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'BountyNotExpired');
+    }
+  });
+
+  it('Admin routes half of the sale fees to reviewers', async () => {
+    const shareBps = 5000;
+
+    const [configAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+
+    try {
+      const shareIx = await program.methods
+        .setReviewerFeeShare(shareBps)
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: configAccountAddress,
+          roleGrant: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(shareIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to set the reviewer fee share');
+    }
+
+    const configAccount = await program.account.paperFiConfig.fetch(
+      configAccountAddress
+    );
+
+    assert.equal(configAccount.reviewerFeeShareBps, shareBps);
+  });
+
+  it('Bond attempts to claim rewards before any new sale', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    try {
+      const claimIx = await program.methods
+        .claimReviewRewards(id)
+        .accountsPartial({
+          signer: bond.publicKey,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bond.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(claimIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bond]);
+
+      assert.fail('Bond was able to claim rewards from an empty pool');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'NothingToClaim');
+    }
+  });
+
+  it('A new reader buys the Paper and Bond claims the reviewer share', async () => {
+    const reader = Keypair.generate();
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+    const [readerAccountAddress, _r] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), reader.publicKey.toBuffer()],
+      programId
+    );
+    const [bobAccountAddress, _bo] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [bobVaultAddress, _bv] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user_vault'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [configAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+    const [configVaultAccountAddress, _cv] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('config_vault'), configAccountAddress.toBuffer()],
+        programId
+      );
+    const [purchaseAccountAddress, _p] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('purchase'),
+          reader.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+    const [authorAccountAddress, _a] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        reader.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    //the reader signs up and buys the paper, funded by the admin
+    try {
+      const fundIx = SystemProgram.transfer({
+        fromPubkey: admin.publicKey,
+        toPubkey: reader.publicKey,
+        lamports: 0.5 * LAMPORTS_PER_SOL,
+      });
+
+      const signupIx = await program.methods
+        .signup('Paper Reader', 'Student')
+        .accounts({
+          signer: reader.publicKey,
+        })
+        .instruction();
+
+      const buyIx = await program.methods
+        .buyPaper(id)
+        .accountsPartial({
+          buyer: reader.publicKey,
+          buyerUserAccount: readerAccountAddress,
+          userAccount: bobAccountAddress,
+          userVault: bobVaultAddress,
+          config: configAccountAddress,
+          configVault: configVaultAccountAddress,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          authorPda: authorAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(fundIx, signupIx, buyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [
+        admin,
+        reader,
+      ]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('The new reader failed to buy the paper');
+    }
+
+    //the reviewer share of the fee is split between the reviews of the paper
+    const configAccount = await program.account.paperFiConfig.fetch(
+      configAccountAddress
+    );
+    const paperBefore = await program.account.paper.fetch(paperAccountAddress);
+    const fee = paperBefore.price.muln(configAccount.fee).divn(100);
+    const reviewerShare = fee
+      .muln(configAccount.reviewerFeeShareBps)
+      .divn(10_000);
+    const expected = reviewerShare.divn(paperBefore.reviews);
+
+    const paperLamportsBefore = await connection.getBalance(paperAccountAddress);
+    const bondBefore = await connection.getBalance(bond.publicKey);
+
+    //the admin pays the transaction fee so Bond's balance only moves by the reward
+    try {
+      const claimIx = await program.methods
+        .claimReviewRewards(id)
+        .accountsPartial({
+          signer: bond.publicKey,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(claimIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [
+        admin,
+        bond,
+      ]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bond failed to claim the reviewer share');
+    }
+
+    const reviewAccount = await program.account.review.fetch(
+      reviewAccountAddress
+    );
+    const paperAfter = await program.account.paper.fetch(paperAccountAddress);
+    const paperLamportsAfter = await connection.getBalance(paperAccountAddress);
+    const bondAfter = await connection.getBalance(bond.publicKey);
+
+    assert.isTrue(expected.gtn(0));
+    assert.equal(reviewAccount.rewardsClaimed.toString(), expected.toString());
+    assert.equal(
+      reviewAccount.rewardDebt.toString(),
+      paperAfter.rewardPool.accPerReview.toString()
+    );
+    assert.isTrue(reviewAccount.rewarded);
+    assert.equal(bondAfter - bondBefore, expected.toNumber());
+    assert.equal(paperLamportsBefore - paperLamportsAfter, expected.toNumber());
+  });
+
+  it('Karen stakes to be eligible to review', async () => {
+    const amount = new BN(0.5 * LAMPORTS_PER_SOL);
+
+    const [stakeAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('stake'), karen.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const stakeIx = await program.methods
+        .stake(amount)
+        .accountsPartial({
+          owner: karen.publicKey,
+          stake: stakeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(stakeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Karen failed to stake');
+    }
+
+    const stakeAccount = await program.account.reviewerStake.fetch(
+      stakeAccountAddress
+    );
+
+    assert.equal(stakeAccount.amount.toString(), amount.toString());
+    assert.equal(stakeAccount.unlockAt.toNumber(), 0);
+  });
+
+  it('Karen attempts to unstake during the cooldown', async () => {
+    const [stakeAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('stake'), karen.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const requestIx = await program.methods
+        .requestUnstake()
+        .accountsPartial({
+          owner: karen.publicKey,
+          stake: stakeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const unstakeIx = await program.methods
+        .unstake()
+        .accountsPartial({
+          owner: karen.publicKey,
+          stake: stakeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(requestIx, unstakeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to unstake before the cooldown ended');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'StakeLocked');
+    }
+  });
+
+  it('Bob, the paper owner, rates the review of Bond as unhelpful', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const [reviewerAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bond.publicKey.toBuffer()],
+      programId
+    );
+
+    const [ratingAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review_rating'),
+        bob.publicKey.toBuffer(),
+        reviewAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const rateIx = await program.methods
+        .rateReview(id, false)
+        .accountsPartial({
+          rater: bob.publicKey,
+          paper: paperAccountAddress,
+          paperOwned: null,
+          review: reviewAccountAddress,
+          reviewerUserAccount: reviewerAccountAddress,
+          rating: ratingAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(rateIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to rate the review');
+    }
+
+    const reviewAccount = await program.account.review.fetch(
+      reviewAccountAddress
+    );
+
+    //owners read their own paper without a purchase
+    assert.equal(reviewAccount.unhelpfulVotes, 1);
+    assert.equal(reviewAccount.helpfulVotes, 0);
+  });
+
+  it('Karen rates the review of Bond as helpful', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const [reviewerAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bond.publicKey.toBuffer()],
+      programId
+    );
+
+    //Karen paid for the paper
+    const [purchaseAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('purchase'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    const [ratingAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review_rating'),
+        karen.publicKey.toBuffer(),
+        reviewAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    const reviewerBefore = await program.account.userAccount.fetch(
+      reviewerAccountAddress
+    );
+
+    try {
+      const rateIx = await program.methods
+        .rateReview(id, true)
+        .accountsPartial({
+          rater: karen.publicKey,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          review: reviewAccountAddress,
+          reviewerUserAccount: reviewerAccountAddress,
+          rating: ratingAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(rateIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Karen failed to rate the review');
+    }
+
+    const reviewAccount = await program.account.review.fetch(
+      reviewAccountAddress
+    );
+    const reviewerAfter = await program.account.userAccount.fetch(
+      reviewerAccountAddress
+    );
+
+    assert.equal(reviewAccount.helpfulVotes, 1);
+    assert.equal(reviewerAfter.helpfulVotes, reviewerBefore.helpfulVotes + 1);
+  });
+
+  it('Karen attempts to rate the same review twice', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [reviewAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    const [reviewerAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bond.publicKey.toBuffer()],
+      programId
+    );
+
+    //Karen paid for the paper
+    const [purchaseAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('purchase'),
+        karen.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    const [ratingAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review_rating'),
+        karen.publicKey.toBuffer(),
+        reviewAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const rateIx = await program.methods
+        .rateReview(id, false)
+        .accountsPartial({
+          rater: karen.publicKey,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          review: reviewAccountAddress,
+          reviewerUserAccount: reviewerAccountAddress,
+          rating: ratingAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(rateIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to rate the same review twice');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'already in use');
+    }
+  });
+
+  it('Bob creates a journal and submits the Paper', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [boardAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const [journalAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _d] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    try {
+      const journalIx = await program.methods
+        .createJournal('PaperFi Letters')
+        .accountsPartial({
+          authority: bob.publicKey,
+          board: boardAccountAddress,
+          journal: journalAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const submitIx = await program.methods
+        .submitPaper(id)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          journal: journalAccountAddress,
+          submission: submissionAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(journalIx, submitIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to create the journal and submit the paper');
+    }
+
+    const journalAccount = await program.account.journal.fetch(
+      journalAccountAddress
+    );
+    const submissionAccount = await program.account.submission.fetch(
+      submissionAccountAddress
+    );
+
+    assert.equal(journalAccount.submissions, 1);
+    assert.deepEqual(submissionAccount.status, { pending: {} });
+  });
+
+  it('Karen attempts to accept the submission without being an editor', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [boardAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const [journalAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _d] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    const [memberAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board_member'),
+        boardAccountAddress.toBuffer(),
+        karen.publicKey.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const decideIx = await program.methods
+        .decideSubmission(true, 'https://arweave.net/decision-letter')
+        .accountsPartial({
+          editor: karen.publicKey,
+          journal: journalAccountAddress,
+          boardMember: null,
+          submission: submissionAccountAddress,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(decideIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to decide a submission');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'NotJournalEditor');
+    }
+  });
+
+  it('Bob attempts to accept the Paper in the journal Bob runs', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [journalAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _d] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    try {
+      const decideIx = await program.methods
+        .decideSubmission(true, 'https://arweave.net/decision-letter')
+        .accountsPartial({
+          editor: bob.publicKey,
+          journal: journalAccountAddress,
+          boardMember: null,
+          submission: submissionAccountAddress,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(decideIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('The paper owner was able to accept the paper');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'EditorIsAuthor');
+    }
+  });
+
+  it('Nancy accepts the Paper in the journal', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [boardAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const [journalAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _d] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    const [memberAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board_member'),
+        boardAccountAddress.toBuffer(),
+        nancy.publicKey.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const decideIx = await program.methods
+        .decideSubmission(true, 'https://arweave.net/decision-letter')
+        .accountsPartial({
+          editor: nancy.publicKey,
+          journal: journalAccountAddress,
+          boardMember: memberAccountAddress,
+          submission: submissionAccountAddress,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(decideIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Nancy failed to accept the paper');
+    }
+
+    const submissionAccount = await program.account.submission.fetch(
+      submissionAccountAddress
+    );
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.deepEqual(submissionAccount.status, { accepted: {} });
+    assert.equal(
+      paperAccount.journal.toString(),
+      journalAccountAddress.toString()
+    );
+  });
+
+  it('Bob invites a hidden reviewer to the Paper', async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const deadline = new BN(now + 14 * 24 * 60 * 60);
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [invitationAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(nancy.publicKey),
+        ],
+        programId
+      );
+
+    try {
+      const inviteIx = await program.methods
+        .inviteReviewer(
+          id,
+          Array.from(invitationCommitment(nancy.publicKey)),
+          invitationReviewKey.publicKey,
+          deadline
+        )
+        .accountsPartial({
+          inviter: bob.publicKey,
+          paper: paperAccountAddress,
+          journal: null,
+          submission: null,
+          boardMember: null,
+          bounty: null,
+          invitation: invitationAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(inviteIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to invite a reviewer');
+    }
+
+    const invitationAccount = await program.account.reviewInvitation.fetch(
+      invitationAccountAddress
+    );
+
+    assert.isNull(invitationAccount.reviewer);
+    assert.deepEqual(invitationAccount.status, { pending: {} });
+  });
+
+  it('Bond attempts to review through the invitation of someone else', async () => {
+    const verdict = { approved: {} };
+    const uri = 'https://arweave.net/invited-review';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [invitationAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(nancy.publicKey),
+        ],
+        programId
+      );
+
+    try {
+      const reviewIx = await program.methods
+        .reviewInvited(id, verdict, uri, null)
+        .accountsPartial({
+          reviewKey: bond.publicKey,
+          paper: paperAccountAddress,
+          invitation: invitationAccountAddress,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bond.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bond]);
+
+      assert.fail('Bond was able to use the invitation of another reviewer');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'Unauthorized');
+    }
+  });
+
+  it('Nancy seals her review with the review key of the invitation', async () => {
+    const verdict = { approved: {} };
+    const uri = 'https://arweave.net/invited-review';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [invitationAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(nancy.publicKey),
+        ],
+        programId
+      );
+
+    try {
+      const reviewIx = await program.methods
+        .reviewInvited(id, verdict, uri, null)
+        .accountsPartial({
+          reviewKey: invitationReviewKey.publicKey,
+          paper: paperAccountAddress,
+          invitation: invitationAccountAddress,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      //the inviter relays the transaction, the wallet of Nancy doesn't sign it
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [
+        bob,
+        invitationReviewKey,
+      ]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Nancy failed to seal her review');
+    }
+
+    const invitationAccount = await program.account.reviewInvitation.fetch(
+      invitationAccountAddress
+    );
+
+    assert.deepEqual(invitationAccount.status, { submitted: {} });
+    assert.deepEqual(invitationAccount.verdict, verdict);
+    assert.equal(invitationAccount.uri, uri);
+    assert.isNull(invitationAccount.reviewer);
+  });
+
+  it('Nancy attempts to reveal her review before the decision is published', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [invitationAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(nancy.publicKey),
+        ],
+        programId
+      );
+
+    const [reviewerAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), nancy.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const revealIx = await program.methods
+        .revealInvitedReview(id, Array.from(invitationSalt))
+        .accountsPartial({
+          signer: nancy.publicKey,
+          reviewerUserAccount: reviewerAccountAddress,
+          paper: paperAccountAddress,
+          invitation: invitationAccountAddress,
+          submission: null,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(revealIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+
+      assert.fail('Nancy revealed her review before the deadline of the invitation');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'RevealPhaseNotOpen');
+    }
+  });
+
+  it('Karen attempts to expire the invitation before its deadline', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [invitationAccountAddress, _b] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(nancy.publicKey),
+        ],
+        programId
+      );
+
+    try {
+      const expireIx = await program.methods
+        .expireReviewAssignment(id, null)
+        .accountsPartial({
+          signer: karen.publicKey,
+          paper: paperAccountAddress,
+          invitation: invitationAccountAddress,
+          reviewerUserAccount: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(expireIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      assert.fail('Karen was able to expire an invitation before its deadline');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvitationNotExpired');
+    }
+  });
+
+  it('Bob reserves the bounty for two short invitations', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const invitationOf = (reviewer: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(reviewer),
+        ],
+        programId
+      )[0];
+
+    //deadlines a few seconds away so the next tests can expire them
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 3);
+    const bountyBefore = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+
+    try {
+      const inviteIxs = [];
+      for (const reviewer of [karen.publicKey, roger.publicKey]) {
+        inviteIxs.push(
+          await program.methods
+            .inviteReviewer(
+              id,
+              Array.from(invitationCommitment(reviewer)),
+              invitationReviewKey.publicKey,
+              deadline
+            )
+            .accountsPartial({
+              inviter: bob.publicKey,
+              paper: paperAccountAddress,
+              journal: null,
+              submission: null,
+              boardMember: null,
+              bounty: bountyAccountAddress,
+              invitation: invitationOf(reviewer),
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction()
+        );
+      }
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(...inviteIxs);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bob failed to invite the reviewers');
+    }
+
+    const bountyAfter = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+    const invitationAccount = await program.account.reviewInvitation.fetch(
+      invitationOf(karen.publicKey)
+    );
+
+    assert.isTrue(invitationAccount.bountyReserved);
+    assert.equal(bountyAfter.reservedReviews, bountyBefore.reservedReviews + 2);
+
+    //let both deadlines pass
+    await new Promise(resolve => setTimeout(resolve, 5000));
+  });
+
+  it('Bob attempts to refund the bounty while invitations hold reservations', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const [bountyVaultAddress, _bu] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty_vault'), bountyAccountAddress.toBuffer()],
+      programId
+    );
+
+    try {
+      const refundIx = await program.methods
+        .refundBounty(id)
+        .accountsPartial({
+          funder: bob.publicKey,
+          paper: paperAccountAddress,
+          bounty: bountyAccountAddress,
+          bountyVault: bountyVaultAddress,
+          bountyTokenVault: null,
+          funderTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(refundIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob refunded a bounty the invitations still hold');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'BountyReserved');
+    }
+  });
+
+  it('Nancy expires the invitation of Karen and records the miss', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const invitationOf = (reviewer: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(reviewer),
+        ],
+        programId
+      )[0];
+
+    const [karenAccountAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), karen.publicKey.toBuffer()],
+      programId
+    );
+
+    const bountyBefore = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+    const karenBefore = await program.account.userAccount.fetch(
+      karenAccountAddress
+    );
+
+    try {
+      const expireIx = await program.methods
+        .expireReviewAssignment(id, Array.from(invitationSalt))
+        .accountsPartial({
+          signer: nancy.publicKey,
+          paper: paperAccountAddress,
+          invitation: invitationOf(karen.publicKey),
+          bounty: bountyAccountAddress,
+          reviewerUserAccount: karenAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(expireIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Nancy failed to expire the invitation');
+    }
+
+    const invitationAccount = await program.account.reviewInvitation.fetch(
+      invitationOf(karen.publicKey)
+    );
+    const bountyAfter = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+    const karenAfter = await program.account.userAccount.fetch(
+      karenAccountAddress
+    );
+
+    assert.deepEqual(invitationAccount.status, { expired: {} });
+    assert.isFalse(invitationAccount.bountyReserved);
+    assert.equal(invitationAccount.reviewer.toString(), karen.publicKey.toString());
+    assert.equal(bountyAfter.reservedReviews, bountyBefore.reservedReviews - 1);
+    assert.equal(karenAfter.missedReviews, karenBefore.missedReviews + 1);
+  });
+
+  it('Bob reassigns the missed invitation of Roger to Bond', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [bountyAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), paperAccountAddress.toBuffer()],
+      programId
+    );
+
+    const invitationOf = (reviewer: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('invitation'),
+          paperAccountAddress.toBuffer(),
+          invitationCommitment(reviewer),
+        ],
+        programId
+      )[0];
+
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 14 * 24 * 60 * 60);
+    const bountyBefore = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+
+    try {
+      const reassignIx = await program.methods
+        .reassignInvitation(
+          id,
+          Array.from(invitationCommitment(bond.publicKey)),
+          invitationReviewKey.publicKey,
+          deadline
+        )
+        .accountsPartial({
+          inviter: bob.publicKey,
+          paper: paperAccountAddress,
+          expiredInvitation: invitationOf(roger.publicKey),
+          invitation: invitationOf(bond.publicKey),
+          bounty: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reassignIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bob failed to reassign the invitation');
+    }
+
+    const invitationAccount = await program.account.reviewInvitation.fetch(
+      invitationOf(bond.publicKey)
+    );
+    const expiredAccount = await connection.getAccountInfo(
+      invitationOf(roger.publicKey)
+    );
+    const bountyAfter = await program.account.reviewBounty.fetch(
+      bountyAccountAddress
+    );
+
+    //the reservation of the old invitation carries over
+    assert.isNull(expiredAccount);
+    assert.isTrue(invitationAccount.bountyReserved);
+    assert.deepEqual(invitationAccount.status, { pending: {} });
+    assert.equal(bountyAfter.reservedReviews, bountyBefore.reservedReviews);
+  });
+
+  it('Bob attempts to create a field reviewer pool without the Editor role', async () => {
+    const name = 'Open Science';
+
+    const [poolAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('reviewer_pool'), bob.publicKey.toBuffer(), Buffer.from(name)],
+      programId
+    );
+
+    try {
+      const poolIx = await program.methods
+        .createReviewerPool(name)
+        .accountsPartial({
+          authority: bob.publicKey,
+          journal: null,
+          roleGrant: null,
+          pool: poolAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(poolIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+      assert.fail('Bob created a field pool without the Editor role');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'Unauthorized');
+    }
+  });
+
+  it('Bob draws a reviewer for the Paper from the journal reviewer pool', async () => {
+    const name = 'Letters Reviewers';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [journalAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _c] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    const [drawAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('reviewer_draw'),
+        journalAccountAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    const [conflictsAccountAddress, _f] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('conflicts'), paperAccountAddress.toBuffer()],
+        programId
+      );
+
+    const poolOf = (poolName: string) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('reviewer_pool'),
+          bob.publicKey.toBuffer(),
+          Buffer.from(poolName),
+        ],
+        programId
+      )[0];
+
+    //Karen is in the conflict list, Roger co-authors the paper and the admin never signed up
+    const reviewers = [
+      karen.publicKey,
+      bond.publicKey,
+      nancy.publicKey,
+      admin.publicKey,
+      roger.publicKey,
+    ];
+
+    //user account and author PDAs of each reviewer: neither the platform nor the
+    //paper asks for a stake, a badge or a board
+    const candidateAccounts = reviewers.flatMap((reviewer) => {
+      const pda = (seeds: Buffer[]) =>
+        PublicKey.findProgramAddressSync(seeds, programId)[0];
+
+      return [
+        pda([Buffer.from('user'), reviewer.toBuffer()]),
+        pda([
+          Buffer.from('author'),
+          reviewer.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ]),
+      ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+    });
+
+    const drawIxFor = (poolName: string, proofs = candidateAccounts) =>
+      program.methods
+        .drawReviewers(id, 1)
+        .accountsPartial({
+          authority: bob.publicKey,
+          pool: poolOf(poolName),
+          paper: paperAccountAddress,
+          submission: submissionAccountAddress,
+          conflicts: conflictsAccountAddress,
+          draw: drawAccountAddress,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(proofs)
+        .instruction();
+
+    const setupIxs = [];
+    for (const poolName of [name, 'Letters Backup']) {
+      setupIxs.push(
+        await program.methods
+          .createReviewerPool(poolName)
+          .accountsPartial({
+            authority: bob.publicKey,
+            journal: journalAccountAddress,
+            roleGrant: null,
+            pool: poolOf(poolName),
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+        await program.methods
+          .updateReviewerPool(reviewers, [])
+          .accountsPartial({
+            authority: bob.publicKey,
+            pool: poolOf(poolName),
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction()
+      );
+    }
+
+    try {
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      //the candidate accounts leave no room for the setup in the draw transaction
+      const setupTx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(...setupIxs);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, setupTx, [bob]);
+
+      //leaving out the user account of Nancy can't make her ineligible
+      const withoutProof = candidateAccounts.map((account, index) =>
+        index === 2 * 2
+          ? { pubkey: programId, isSigner: false, isWritable: false }
+          : account
+      );
+      try {
+        const steeredIx = await drawIxFor(name, withoutProof);
+
+        const steeredTx = new anchor.web3.Transaction({
+          feePayer: bob.publicKey,
+          blockhash: blockhashContext.blockhash,
+          lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+        }).add(
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+          steeredIx
+        );
+
+        await anchor.web3.sendAndConfirmTransaction(connection, steeredTx, [bob]);
+        assert.fail('Bob drew without the user account of Nancy');
+      } catch (e: any) {
+        const logs = e.logs ? e.logs.join('\n') : e.message;
+        assert.include(logs, 'InvalidCandidateAccounts');
+      }
+
+      const drawIx = await drawIxFor(name);
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(
+        anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+        drawIx
+      );
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Bob failed to draw reviewers');
+    }
+
+    const drawAccount = await program.account.reviewerDraw.fetch(
+      drawAccountAddress
+    );
+    const drawn = drawAccount.reviewers.map((reviewer) => reviewer.toString());
+
+    assert.equal(drawAccount.candidates, 2);
+    assert.equal(drawn.length, 1);
+    assert.include(
+      [bond.publicKey.toString(), nancy.publicKey.toString()],
+      drawn[0]
+    );
+
+    //another pool of the journal can't reroll the draw
+    try {
+      const rerollIx = await drawIxFor('Letters Backup');
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(
+        anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+        rerollIx
+      );
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+      assert.fail('Bob rerolled the draw with another pool');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'already in use');
+    }
+  });
+
+  it('Bob attempts to appeal a delisting the reviews did not cause', async () => {
+    const rationaleUri = 'https://arweave.net/delisting-appeal';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [appealAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('appeal'), paperAccountAddress.toBuffer(), paperVersion],
+      programId
+    );
+
+    try {
+      const appealIx = await program.methods
+        .appealDelisting(id, rationaleUri)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          journal: null,
+          appeal: appealAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(appealIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to appeal a paper that is still listed');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'NotDelistedByReviews');
+    }
+  });
+
+  it('Bob attempts to open a dispute against his own paper', async () => {
+    const evidenceUri = 'https://arweave.net/priority-evidence';
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    const [disputeAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('dispute'),
+        paperAccountAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const disputeIx = await program.methods
+        .openDispute(id, id, { priority: {} }, evidenceUri)
+        .accountsPartial({
+          claimant: bob.publicKey,
+          claimantPaper: paperAccountAddress,
+          respondentPaper: paperAccountAddress,
+          dispute: disputeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(disputeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to dispute his own paper');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvalidDispute');
+    }
+  });
+
+  //------------ Paper Versions Tests ------------------
+  it('Bob publishes a free paper that Bond buys and reviews', async () => {
+    const [bobAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [bobVaultAddress, _v] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user_vault'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [bondAccountAddress, _bo] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bond.publicKey.toBuffer()],
+      programId
+    );
+    const [configAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+    const [configVaultAccountAddress, _cv] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('config_vault'), configAccountAddress.toBuffer()],
+        programId
+      );
+    const [paperAccountAddress, _p] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+    const [bobAuthorAddress, _ba] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        bob.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+    const [bondAuthorAddress, _bna] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+    const [purchaseAccountAddress, _pu] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('purchase'),
+          bond.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+    const [reviewAccountAddress, _r] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        paperVersion,
+      ],
+      programId
+    );
+
+    try {
+      const newPaperIx = await program.methods
+        .newPaper(id3, 'https://arweave.net/paper-info', new BN(0), 'ar://paper-3')
+        .accountsPartial({
+          owner: bob.publicKey,
+          userAccount: bobAccountAddress,
+          paper: paperAccountAddress,
+          paperAuthor: bobAuthorAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const buyIx = await program.methods
+        .buyPaper(id3)
+        .accountsPartial({
+          buyer: bond.publicKey,
+          buyerUserAccount: bondAccountAddress,
+          userAccount: bobAccountAddress,
+          userVault: bobVaultAddress,
+          config: configAccountAddress,
+          configVault: configVaultAccountAddress,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          authorPda: bondAuthorAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const reviewIx = await program.methods
+        .reviewPaper(id3, { approved: {} }, 'https://arweave.net/review-3', null, null)
+        .accountsPartial({
+          signer: bond.publicKey,
+          reviewerUserAccount: bondAccountAddress,
+          userAccount: bobAccountAddress,
+          authorPda: bondAuthorAddress,
+          paperOwned: purchaseAccountAddress,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(newPaperIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      const tx2 = new anchor.web3.Transaction({
+        feePayer: bond.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(buyIx, reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx2, [bond]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Failed to publish, buy and review the free paper');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+    assert.equal(paperAccount.versionReviews, 1);
+    assert.equal(paperAccount.reviewStatus.approved.toNumber(), 1);
+  });
+
+  it('Bob attempts to change the paper content without a new version', async () => {
+    const editPaperParams = {
+      paperInfoUrl: null,
+      listed: null,
+      price: null,
+      version: null,
+      paperUri: 'ar://paper-3-revised',
+    };
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const editIx = await program.methods
+        .editPaper(id3, editPaperParams)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          paperVersion: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to swap the reviewed content in place');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'VersionBumpRequired');
+    }
+  });
+
+  it('Bob publishes version 2 of the paper', async () => {
+    const editPaperParams = {
+      paperInfoUrl: null,
+      listed: null,
+      price: null,
+      version: 2,
+      paperUri: 'ar://paper-3-revised',
+    };
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+
+    //archive of the version being replaced
+    const [versionAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper_version'), paperAccountAddress.toBuffer(), paperVersion],
+      programId
+    );
+
+    try {
+      const editIx = await program.methods
+        .editPaper(id3, editPaperParams)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          paperVersion: versionAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bob failed to publish version 2');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+    const archive = await program.account.paperVersion.fetch(
+      versionAccountAddress
+    );
+
+    //the archive keeps the content and the tallies of version 1
+    assert.equal(archive.version, 1);
+    assert.equal(archive.paperUri, 'ar://paper-3');
+    assert.equal(archive.reviews, 1);
+    assert.equal(archive.reviewStatus.approved.toNumber(), 1);
+
+    //version 2 starts a fresh tally
+    assert.equal(paperAccount.version, 2);
+    assert.equal(paperAccount.paperUri, 'ar://paper-3-revised');
+    assert.equal(paperAccount.versionReviews, 0);
+    assert.equal(paperAccount.reviewStatus.approved.toNumber(), 0);
+    assert.equal(paperAccount.reviewStatus.weightedApproved.toNumber(), 0);
+  });
+
+  it('Bond reviews version 2 without the paper counting twice on his profile', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+    const [bondAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bond.publicKey.toBuffer()],
+      programId
+    );
+    const [bobAccountAddress, _o] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [reviewAccountAddress, _r] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('review'),
+        bond.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+        new BN(2).toArrayLike(Buffer, 'le', 4),
+      ],
+      programId
+    );
+
+    const bondBefore = await program.account.userAccount.fetch(bondAccountAddress);
+
+    try {
+      const reviewIx = await program.methods
+        .reviewPaper(id3, { approved: {} }, 'https://arweave.net/review-3-v2', null, null)
+        .accountsPartial({
+          signer: bond.publicKey,
+          reviewerUserAccount: bondAccountAddress,
+          userAccount: bobAccountAddress,
+          paper: paperAccountAddress,
+          review: reviewAccountAddress,
+          badgeAsset: null,
+          boardMember: null,
+          stake: null,
+          bounty: null,
+          bountyVault: null,
+          bountyTokenVault: null,
+          reviewerTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bond.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(reviewIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bond]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bond failed to review version 2');
+    }
+
+    const bondAfter = await program.account.userAccount.fetch(bondAccountAddress);
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    //the re-review counts for version 2, not as another paper reviewed
+    assert.equal(paperAccount.versionReviews, 1);
+    assert.equal(bondAfter.reviews, bondBefore.reviews);
+    assert.equal(bondAfter.versionReviews, bondBefore.versionReviews + 1);
+  });
+
+  it('Nancy attempts to accept a submission made before a new version', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+
+    const [journalAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('journal'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Letters'),
+      ],
+      programId
+    );
+
+    const [submissionAccountAddress, _d] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('submission'),
+          journalAccountAddress.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+
+    const [boardAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const [memberAccountAddress, _e] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board_member'),
+        boardAccountAddress.toBuffer(),
+        nancy.publicKey.toBuffer(),
+      ],
+      programId
+    );
+
+    //archive of version 2, replaced after the submission
+    const [versionAccountAddress, _v] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('paper_version'),
+        paperAccountAddress.toBuffer(),
+        new BN(2).toArrayLike(Buffer, 'le', 4),
+      ],
+      programId
+    );
+
+    //Bob submits version 2 and publishes version 3 right after
+    try {
+      const submitIx = await program.methods
+        .submitPaper(id3)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          journal: journalAccountAddress,
+          submission: submissionAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const editIx = await program.methods
+        .editPaper(id3, {
+          paperInfoUrl: null,
+          listed: null,
+          price: null,
+          version: 3,
+          paperUri: 'ar://paper-3-final',
+        })
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          paperVersion: versionAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(submitIx, editIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Bob failed to submit and revise the paper');
+    }
+
+    try {
+      const decideIx = await program.methods
+        .decideSubmission(true, 'https://arweave.net/decision-letter')
+        .accountsPartial({
+          editor: nancy.publicKey,
+          journal: journalAccountAddress,
+          boardMember: memberAccountAddress,
+          submission: submissionAccountAddress,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(decideIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+
+      assert.fail('Nancy was able to accept a stale submission');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'StaleSubmission');
+    }
+  });
+
+  //------------ Dispute Ruling Tests ------------------
+  it('Admin grants the Arbitrator role to Nancy', async () => {
+    const [roleGrantAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), nancy.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const grantIx = await program.methods
+        .grantRole(nancy.publicKey, { arbitrator: {} })
+        .accountsPartial({
+          admin: admin.publicKey,
+          adminRole: null,
+          roleGrant: roleGrantAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(grantIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Admin failed to grant the Arbitrator role');
+    }
+
+    const roleGrant = await program.account.roleGrant.fetch(roleGrantAddress);
+    assert.equal(roleGrant.wallet.toString(), nancy.publicKey.toString());
+  });
+
+  it('Karen publishes a paper and disputes the priority of the Paper', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+    const [karenPaperAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), karen.publicKey.toBuffer(), id4.toBuffer('le', 8)],
+      programId
+    );
+    const [disputeAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('dispute'),
+        karenPaperAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+    const [karenAccountAddress, _u] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), karen.publicKey.toBuffer()],
+      programId
+    );
+    const [karenAuthorAddress, _a] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        karen.publicKey.toBuffer(),
+        karenPaperAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const newPaperIx = await program.methods
+        .newPaper(id4, 'https://arweave.net/karen-info', new BN(0), 'ar://karen-paper')
+        .accountsPartial({
+          owner: karen.publicKey,
+          userAccount: karenAccountAddress,
+          paper: karenPaperAddress,
+          paperAuthor: karenAuthorAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const disputeIx = await program.methods
+        .openDispute(id4, id, { priority: {} }, 'https://arweave.net/karen-priority')
+        .accountsPartial({
+          claimant: karen.publicKey,
+          claimantPaper: karenPaperAddress,
+          respondentPaper: paperAccountAddress,
+          dispute: disputeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(newPaperIx, disputeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Karen failed to open the dispute');
+    }
+
+    const disputeAccount = await program.account.dispute.fetch(
+      disputeAccountAddress
+    );
+    assert.deepEqual(disputeAccount.status, { open: {} });
+    assert.equal(disputeAccount.respondent.toString(), bob.publicKey.toString());
+  });
+
+  it('Karen requires reviewers to be members of the board before any review', async () => {
+    const [boardAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('board'),
+        bob.publicKey.toBuffer(),
+        Buffer.from('PaperFi Reviewers'),
+      ],
+      programId
+    );
+
+    const requirements = {
+      minAccountAge: new BN(0),
+      minPapers: 0,
+      badgeCollection: null,
+      editorialBoard: boardAccountAddress,
+    };
+
+    const [paperAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), karen.publicKey.toBuffer(), id4.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const requirementsIx = await program.methods
+        .setPaperRequirements(id4, requirements)
+        .accountsPartial({
+          owner: karen.publicKey,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(requirementsIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.message);
+      assert.fail('Karen failed to set the reviewer requirements');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.equal(
+      paperAccount.reviewerRequirements.editorialBoard.toString(),
+      boardAccountAddress.toString()
+    );
+  });
+
+  it('Admin votes to redirect the proceeds of the Paper', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+    const [karenPaperAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), karen.publicKey.toBuffer(), id4.toBuffer('le', 8)],
+      programId
+    );
+    const [disputeAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('dispute'),
+        karenPaperAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const ruleIx = await program.methods
+        .ruleDispute(true, false, true, 'https://arweave.net/ruling-admin')
+        .accountsPartial({
+          arbitrator: admin.publicKey,
+          roleGrant: null,
+          dispute: disputeAccountAddress,
+          respondentPaper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(ruleIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Admin failed to vote on the dispute');
+    }
+
+    //one vote does not decide the dispute
+    const disputeAccount = await program.account.dispute.fetch(
+      disputeAccountAddress
+    );
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.deepEqual(disputeAccount.status, { open: {} });
+    assert.equal(disputeAccount.votes.length, 1);
+    assert.isNull(paperAccount.proceedsRecipient);
+  });
+
+  it('Nancy upholds the dispute and the proceeds go to Karen', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+    const [karenPaperAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), karen.publicKey.toBuffer(), id4.toBuffer('le', 8)],
+      programId
+    );
+    const [disputeAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('dispute'),
+        karenPaperAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+    const [roleGrantAddress, _r] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), nancy.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      //Nancy also asks for a takedown, only the remedies both arbitrators voted apply
+      const ruleIx = await program.methods
+        .ruleDispute(true, true, true, 'https://arweave.net/ruling-nancy')
+        .accountsPartial({
+          arbitrator: nancy.publicKey,
+          roleGrant: roleGrantAddress,
+          dispute: disputeAccountAddress,
+          respondentPaper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(ruleIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Nancy failed to vote on the dispute');
+    }
+
+    const disputeAccount = await program.account.dispute.fetch(
+      disputeAccountAddress
+    );
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.deepEqual(disputeAccount.status, { upheld: {} });
+    assert.isTrue(disputeAccount.proceedsRedirected);
+    assert.isFalse(disputeAccount.takenDown);
+    assert.equal(disputeAccount.arbitrator.toString(), nancy.publicKey.toString());
+    assert.equal(paperAccount.proceedsRecipient.toString(), karen.publicKey.toString());
+    assert.deepEqual(paperAccount.moderation.status, { active: {} });
+  });
+
+  it('Bob attempts to change the price while the proceeds are redirected', async () => {
+    const editPaperParams = {
+      paperInfoUrl: null,
+      listed: null,
+      price: new BN(0),
+      version: null,
+      paperUri: null,
+    };
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const editIx = await program.methods
+        .editPaper(id, editPaperParams)
+        .accountsPartial({
+          owner: bob.publicKey,
+          paper: paperAccountAddress,
+          paperVersion: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(editIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob was able to change the price of a redirected paper');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'ProceedsRedirected');
+    }
+  });
+
+  it('A new reader buys the Paper and pays Karen', async () => {
+    const reader = Keypair.generate();
+
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+    const [readerAccountAddress, _r] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), reader.publicKey.toBuffer()],
+      programId
+    );
+    const [karenAccountAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), karen.publicKey.toBuffer()],
+      programId
+    );
+    const [karenVaultAddress, _kv] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user_vault'), karen.publicKey.toBuffer()],
+      programId
+    );
+    const [bobVaultAddress, _bv] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user_vault'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [configAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+    const [configVaultAccountAddress, _cv] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('config_vault'), configAccountAddress.toBuffer()],
+        programId
+      );
+    const [purchaseAccountAddress, _p] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('purchase'),
+          reader.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+    const [authorAccountAddress, _a] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        reader.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+    const karenVaultBefore = await connection.getBalance(karenVaultAddress);
+    const bobVaultBefore = await connection.getBalance(bobVaultAddress);
+
+    //the vault of the payee, not the one of the owner, receives the sale
+    try {
+      const fundIx = SystemProgram.transfer({
+        fromPubkey: admin.publicKey,
+        toPubkey: reader.publicKey,
+        lamports: 0.5 * LAMPORTS_PER_SOL,
+      });
+
+      const signupIx = await program.methods
+        .signup('Second Reader', 'Student')
+        .accounts({
+          signer: reader.publicKey,
+        })
+        .instruction();
+
+      const buyIx = await program.methods
+        .buyPaper(id)
+        .accountsPartial({
+          buyer: reader.publicKey,
+          buyerUserAccount: readerAccountAddress,
+          userAccount: karenAccountAddress,
+          userVault: karenVaultAddress,
+          config: configAccountAddress,
+          configVault: configVaultAccountAddress,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          authorPda: authorAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(fundIx, signupIx, buyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [
+        admin,
+        reader,
+      ]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('The new reader failed to buy the redirected paper');
+    }
+
+    const karenVaultAfter = await connection.getBalance(karenVaultAddress);
+    const bobVaultAfter = await connection.getBalance(bobVaultAddress);
+
+    assert.equal(karenVaultAfter - karenVaultBefore, paperAccount.price.toNumber());
+    assert.equal(bobVaultAfter, bobVaultBefore);
+  });
+
+  it('Bob attempts to lift the redirect of the proceeds of his Paper', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const liftIx = await program.methods
+        .liftProceedsRedirect(id)
+        .accountsPartial({
+          signer: bob.publicKey,
+          roleGrant: null,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: bob.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(liftIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [bob]);
+
+      assert.fail('Bob lifted the redirect ruled against his paper');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'Unauthorized');
+    }
+  });
+
+  it('Karen waives the redirected proceeds of the Paper', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id.toBuffer('le', 8)],
+      programId
+    );
+
+    try {
+      const liftIx = await program.methods
+        .liftProceedsRedirect(id)
+        .accountsPartial({
+          signer: karen.publicKey,
+          roleGrant: null,
+          paper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(liftIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('Karen failed to waive the redirected proceeds');
+    }
+
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    //the sales and the price are back with Bob
+    assert.isNull(paperAccount.proceedsRecipient);
+  });
+
+  it('Admin attempts to set a dispute quorum above the majority of the panel', async () => {
+    try {
+      const quorumIx = await program.methods
+        .setDisputeQuorum(3)
+        .accountsPartial({
+          admin: admin.publicKey,
+          roleGrant: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(quorumIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [admin]);
+
+      assert.fail('Admin set a quorum the panel could split under');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'InvalidQuorum');
+    }
+  });
+
+  it('Karen disputes the free paper and the arbitrators take it down', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+    const [karenPaperAddress, _k] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), karen.publicKey.toBuffer(), id4.toBuffer('le', 8)],
+      programId
+    );
+    const [disputeAccountAddress, _d] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('dispute'),
+        karenPaperAddress.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+    const [roleGrantAddress, _r] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), nancy.publicKey.toBuffer()],
+      programId
+    );
+
+    try {
+      const disputeIx = await program.methods
+        .openDispute(id4, id3, { plagiarism: {} }, 'https://arweave.net/karen-plagiarism')
+        .accountsPartial({
+          claimant: karen.publicKey,
+          claimantPaper: karenPaperAddress,
+          respondentPaper: paperAccountAddress,
+          dispute: disputeAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: karen.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(disputeIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [karen]);
+
+      const adminRuleIx = await program.methods
+        .ruleDispute(true, true, false, 'https://arweave.net/takedown-admin')
+        .accountsPartial({
+          arbitrator: admin.publicKey,
+          roleGrant: null,
+          dispute: disputeAccountAddress,
+          respondentPaper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const nancyRuleIx = await program.methods
+        .ruleDispute(true, true, false, 'https://arweave.net/takedown-nancy')
+        .accountsPartial({
+          arbitrator: nancy.publicKey,
+          roleGrant: roleGrantAddress,
+          dispute: disputeAccountAddress,
+          respondentPaper: paperAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const tx2 = new anchor.web3.Transaction({
+        feePayer: admin.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(adminRuleIx, nancyRuleIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx2, [admin, nancy]);
+    } catch (e: any) {
+      console.log(e.logs);
+      assert.fail('The arbitrators failed to take the paper down');
+    }
+
+    const disputeAccount = await program.account.dispute.fetch(
+      disputeAccountAddress
+    );
+    const paperAccount = await program.account.paper.fetch(paperAccountAddress);
+
+    assert.deepEqual(disputeAccount.status, { upheld: {} });
+    assert.isTrue(disputeAccount.takenDown);
+    assert.isFalse(disputeAccount.proceedsRedirected);
+    assert.isNull(paperAccount.proceedsRecipient);
+    assert.isFalse(paperAccount.listed);
+    assert.deepEqual(paperAccount.moderation.status, { takenDown: {} });
+    assert.equal(paperAccount.moderation.appealDeadline.toNumber(), 0);
+  });
+
+  it('Nancy attempts to buy the paper taken down by the arbitrators', async () => {
+    const [paperAccountAddress, _] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paper'), bob.publicKey.toBuffer(), id3.toBuffer('le', 8)],
+      programId
+    );
+    const [nancyAccountAddress, _n] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), nancy.publicKey.toBuffer()],
+      programId
+    );
+    const [bobAccountAddress, _b] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [bobVaultAddress, _bv] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('user_vault'), bob.publicKey.toBuffer()],
+      programId
+    );
+    const [configAccountAddress, _c] = await PublicKey.findProgramAddressSync(
+      [Buffer.from('paperfi_config')],
+      programId
+    );
+    const [configVaultAccountAddress, _cv] =
+      await PublicKey.findProgramAddressSync(
+        [Buffer.from('config_vault'), configAccountAddress.toBuffer()],
+        programId
+      );
+    const [purchaseAccountAddress, _p] =
+      await PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('purchase'),
+          nancy.publicKey.toBuffer(),
+          paperAccountAddress.toBuffer(),
+        ],
+        programId
+      );
+    const [authorAccountAddress, _a] = await PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('author'),
+        nancy.publicKey.toBuffer(),
+        paperAccountAddress.toBuffer(),
+      ],
+      programId
+    );
+
+    try {
+      const buyIx = await program.methods
+        .buyPaper(id3)
+        .accountsPartial({
+          buyer: nancy.publicKey,
+          buyerUserAccount: nancyAccountAddress,
+          userAccount: bobAccountAddress,
+          userVault: bobVaultAddress,
+          config: configAccountAddress,
+          configVault: configVaultAccountAddress,
+          paper: paperAccountAddress,
+          paperOwned: purchaseAccountAddress,
+          authorPda: authorAccountAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const blockhashContext = await connection.getLatestBlockhash();
+
+      const tx = new anchor.web3.Transaction({
+        feePayer: nancy.publicKey,
+        blockhash: blockhashContext.blockhash,
+        lastValidBlockHeight: blockhashContext.lastValidBlockHeight,
+      }).add(buyIx);
+
+      await anchor.web3.sendAndConfirmTransaction(connection, tx, [nancy]);
+
+      assert.fail('Nancy bought a paper taken down by the arbitrators');
+    } catch (e: any) {
+      const logs = e.logs ? e.logs.join('\n') : e.message;
+      assert.include(logs, 'PaperTakenDown');
+    }
+  });
 });